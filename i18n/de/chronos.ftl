@@ -26,3 +26,208 @@ pomodoro-paused = Pomodoro pausiert
 pomodoro-paused-des = Deine Pomodoro-Session wurde pausiert
 pomodoro-stopped = Pomodoro gestoppt
 pomodoro-stopped-des = Deine Pomodoro-Session wurde pausiert und gestoppt
+
+task-placeholder = Woran arbeitest du?
+
+// hooks
+hooks = Hooks
+hook-placeholder = Pfad zu einer ausführbaren Datei
+hook-focus-start = Fokus gestartet
+hook-focus-end = Fokus beendet
+hook-break-start = Pause gestartet
+hook-break-end = Pause beendet
+hook-paused = Timer pausiert
+hook-reset = Timer zurückgesetzt
+hook-timeout = Zeitlimit für Hooks
+seconds = Sekunden
+
+// webhooks
+webhooks = Webhooks
+webhook-placeholder = http://localhost:8080/chronos
+add-webhook = Webhook hinzufügen
+webhook-timeout = Zeitlimit der Anfrage
+webhook-retries = Wiederholungen
+
+// Fokusblöcke
+focus-blocks = Während Fokusblöcken
+do-not-disturb = „Nicht stören“ aktivieren
+inhibit-sleep = Bildschirm eingeschaltet lassen und Bereitschaft verhindern
+auto-pause-on-lock = Pausieren, wenn der Bildschirm gesperrt wird oder das System in Bereitschaft geht
+auto-pause-on-idle = Pausieren, wenn ich inaktiv bin
+
+// abwesend
+away-returned = Willkommen zurück
+away-returned-des = Dein Fokusblock wurde pausiert, während du { $duration } weg warst.
+away-resume = Fortsetzen
+away-count = Zeit anrechnen
+away-discard = Block verwerfen
+
+// Töne
+sounds = Töne
+activate-sounds = Ton abspielen, wenn eine Phase endet
+sound-focus-end = Ende des Fokus
+sound-break-end = Ende der Pause
+sound-default = Standardton oder Pfad zu einer Audiodatei
+sound-preview = Vorhören
+sound-volume = Lautstärke
+ambience = Hintergrundgeräusch während des Fokus
+ambience-off = Keines
+ambience-tick = Tickende Uhr
+ambience-white-noise = Weißes Rauschen
+ambience-brown-noise = Braunes Rauschen
+ambience-volume = Lautstärke des Hintergrundgeräuschs
+
+// Pausen
+breaks = Pausen
+break-overlay = Bildschirm während Pausen abdecken
+break-postpone-minutes = Pause verschieben um
+break-postpone-limit = Erlaubte Verschiebungen pro Pause
+break-time = Zeit für eine Pause
+break-skip = Pause überspringen
+break-postpone = { $minutes } Min. verschieben (noch { $left })
+break-suggestion-stretch = Steh auf und dehne Rücken und Schultern.
+break-suggestion-water = Trink ein Glas Wasser.
+break-suggestion-eyes = Schau 20 Sekunden lang auf etwas in sechs Metern Entfernung.
+break-suggestion-walk = Mach einen kurzen Spaziergang.
+break-suggestion-breathe = Atme ein paar Mal langsam und tief durch.
+break-suggestion-window = Öffne ein Fenster und schnapp frische Luft.
+
+compact-mode = Kompaktmodus
+compact-mode-leave = Kompaktmodus verlassen
+
+// Fenster
+window = Fenster
+compact-hide-header = Kopfleiste im Kompaktmodus ausblenden
+
+// Tastenkürzel
+shortcuts = Tastenkürzel
+shortcut-placeholder = Deaktiviert
+shortcut-invalid = Verwende ein Kürzel wie Ctrl+Shift+S
+shortcut-taken = Bereits von einem anderen Kürzel belegt
+shortcut-page = { $page } anzeigen
+start-pause = Starten oder pausieren
+skip = Überspringen
+reset = Zurücksetzen
+
+title-countdown = Verbleibende Zeit im Fenstertitel anzeigen
+
+// Phasen
+phase-focus = Fokus
+phase-short-break = Kurze Pause
+phase-long-break = Lange Pause
+state-stopped = Bereit
+state-running = Läuft
+state-paused = Pausiert
+
+// Stoppuhr
+stopwatch = Stoppuhr
+stopwatch-lap = Runde
+stopwatch-laps = Runden
+stopwatch-lap-number = Runde { $number }
+copy = Kopieren
+
+// Timer
+timers = Timer
+timer-name = Name, etwa Tee oder Standup
+add-timer = Timer hinzufügen
+timer-finished = Die Zeit ist um
+
+// Wecker
+alarms = Wecker
+alarm = Wecker
+alarm-label = Bezeichnung
+add-alarm = Wecker hinzufügen
+alarm-snooze = Schlummern
+alarm-dismiss = Beenden
+alarm-snooze-minutes = Schlummern für
+alarm-once = Einmalig
+alarm-every-day = Täglich
+weekday-mon = Mo
+weekday-tue = Di
+weekday-wed = Mi
+weekday-thu = Do
+weekday-fri = Fr
+weekday-sat = Sa
+weekday-sun = So
+
+month-jan = Jan.
+month-feb = Feb.
+month-mar = März
+month-apr = Apr.
+month-may = Mai
+month-jun = Juni
+month-jul = Juli
+month-aug = Aug.
+month-sep = Sept.
+month-oct = Okt.
+month-nov = Nov.
+month-dec = Dez.
+
+// Weltuhr
+world-clock = Weltuhr
+world-clock-local = Ortszeit
+world-clock-search = Stadt oder Zeitzone hinzufügen
+world-clock-unavailable = Zeitzone nicht gefunden
+world-clock-planner = Besprechung um
+working-hours = Arbeitszeit
+today = Heute
+tomorrow = Morgen
+yesterday = Gestern
+days-ahead = { $days } Tage später
+days-behind = { $days } Tage früher
+day-date = { $weekday }, { $day }. { $month } { $year }
+
+statistics = Statistik
+statistics-week = Letzte 7 Tage
+statistics-month = Letzte 30 Tage
+statistics-focus = Fokus
+statistics-interruptions = Unterbrechungen
+statistics-days = Tage
+statistics-tasks = Aufgaben
+statistics-focus-blocks = Fokusblöcke
+statistics-focus-time = Fokuszeit
+statistics-break-time = Pausenzeit
+statistics-rating = Durchschnittliche Bewertung
+statistics-interruptions-per-block = Pro Fokusblock
+
+mode-pomodoro = Pomodoro
+mode-flowtime = Flowtime
+flowtime-take-break = Pause machen
+flowtime-break-ratio = Länge der Flowtime-Pause
+
+daily-goal = Tagesziel
+goal-pomodoros = Pomodoros
+daily-goal-amount = Fokus pro Tag
+daily-goal-amount-des = Auf 0 setzen, um kein Ziel zu haben
+daily-goal-reached = Tagesziel erreicht
+daily-goal-reached-des = Du hast das heutige Ziel von { $goal } erreicht.
+daily-summary = Tageszusammenfassung
+daily-summary-time = Zeit der Zusammenfassung
+daily-summary-des = Heute { $blocks } Fokusblöcke, { $focus } Fokus und { $breaks } Pausen.
+daily-summary-tasks = Aufgaben: { $tasks }
+
+cancel = Abbrechen
+interruption-log = Unterbrechung erfassen
+interruption-reason = Was hat dich unterbrochen?
+interruption-note = Notiz (optional)
+interruption-internal = Intern
+interruption-external = Extern
+interruptions-count = Unterbrechungen: { $count }
+interruptions-summary = Unterbrechungen: { $internal } intern, { $external } extern
+
+save = Speichern
+history-search = Notizen und Aufgaben durchsuchen
+reflection-prompt = Nach jedem Fokusblock nach Notiz und Bewertung fragen
+reflection-title = Wie lief dieser Fokusblock?
+reflection-note = Was hast du geschafft?
+reflection-rating = Fokus
+
+delete = Löschen
+entry-add = Einen Block abseits des Computers hinzufügen
+entry-edit = Eintrag bearbeiten
+entry-date = Tag
+entry-duration = Dauer
+entry-end = Beendet um
+
+history-show-breaks = Pausen anzeigen
+history-show-more = Ältere Tage anzeigen
@@ -26,3 +26,208 @@ pomodoro-paused = Pomodoro paused
 pomodoro-paused-des = Your pomodoro session has been paused
 pomodoro-stopped = Pomodoro stopped
 pomodoro-stopped-des = Pomodoro has been stopped and resetted
+
+task-placeholder = What are you working on?
+
+// hooks
+hooks = Hooks
+hook-placeholder = Path to an executable
+hook-focus-start = Focus started
+hook-focus-end = Focus ended
+hook-break-start = Break started
+hook-break-end = Break ended
+hook-paused = Timer paused
+hook-reset = Timer reset
+hook-timeout = Hook timeout
+seconds = seconds
+
+// webhooks
+webhooks = Webhooks
+webhook-placeholder = http://localhost:8080/chronos
+add-webhook = Add webhook
+webhook-timeout = Request timeout
+webhook-retries = Retries
+
+// focus blocks
+focus-blocks = During focus blocks
+do-not-disturb = Turn on Do Not Disturb
+inhibit-sleep = Keep the screen on and prevent suspend
+auto-pause-on-lock = Pause when the screen locks or the system suspends
+auto-pause-on-idle = Pause when I'm idle
+
+// away
+away-returned = Welcome back
+away-returned-des = Your focus block was paused while you were away for { $duration }.
+away-resume = Resume
+away-count = Count the time
+away-discard = Discard block
+
+// sounds
+sounds = Sounds
+activate-sounds = Play a sound when a phase ends
+sound-focus-end = End of focus
+sound-break-end = End of break
+sound-default = Default sound, or path to a sound file
+sound-preview = Preview
+sound-volume = Volume
+ambience = Background sound during focus
+ambience-off = None
+ambience-tick = Ticking clock
+ambience-white-noise = White noise
+ambience-brown-noise = Brown noise
+ambience-volume = Background sound volume
+
+// breaks
+breaks = Breaks
+break-overlay = Cover the screen during breaks
+break-postpone-minutes = Postpone a break by
+break-postpone-limit = Postpones allowed per break
+break-time = Time for a break
+break-skip = Skip break
+break-postpone = Postpone { $minutes } min ({ $left } left)
+break-suggestion-stretch = Stand up and stretch your back and shoulders.
+break-suggestion-water = Drink a glass of water.
+break-suggestion-eyes = Look at something 20 feet away for 20 seconds.
+break-suggestion-walk = Take a short walk.
+break-suggestion-breathe = Take a few slow, deep breaths.
+break-suggestion-window = Open a window and get some fresh air.
+
+compact-mode = Compact mode
+compact-mode-leave = Leave compact mode
+
+// window
+window = Window
+compact-hide-header = Hide the header bar in compact mode
+
+// shortcuts
+shortcuts = Keyboard shortcuts
+shortcut-placeholder = Disabled
+shortcut-invalid = Use a binding like Ctrl+Shift+S
+shortcut-taken = Already used by another shortcut
+shortcut-page = Show { $page }
+start-pause = Start or pause
+skip = Skip
+reset = Reset
+
+title-countdown = Show the remaining time in the window title
+
+// phases
+phase-focus = Focus
+phase-short-break = Short break
+phase-long-break = Long break
+state-stopped = Ready
+state-running = Running
+state-paused = Paused
+
+// stopwatch
+stopwatch = Stopwatch
+stopwatch-lap = Lap
+stopwatch-laps = Laps
+stopwatch-lap-number = Lap { $number }
+copy = Copy
+
+// timers
+timers = Timers
+timer-name = Name, like Tea or Standup
+add-timer = Add timer
+timer-finished = Time is up
+
+// alarms
+alarms = Alarms
+alarm = Alarm
+alarm-label = Label
+add-alarm = Add alarm
+alarm-snooze = Snooze
+alarm-dismiss = Dismiss
+alarm-snooze-minutes = Snooze for
+alarm-once = Once
+alarm-every-day = Every day
+weekday-mon = Mon
+weekday-tue = Tue
+weekday-wed = Wed
+weekday-thu = Thu
+weekday-fri = Fri
+weekday-sat = Sat
+weekday-sun = Sun
+
+month-jan = Jan
+month-feb = Feb
+month-mar = Mar
+month-apr = Apr
+month-may = May
+month-jun = Jun
+month-jul = Jul
+month-aug = Aug
+month-sep = Sep
+month-oct = Oct
+month-nov = Nov
+month-dec = Dec
+
+// world clock
+world-clock = World clock
+world-clock-local = Local time
+world-clock-search = Add a city or time zone
+world-clock-unavailable = Time zone not found
+world-clock-planner = Meeting at
+working-hours = Working hours
+today = Today
+tomorrow = Tomorrow
+yesterday = Yesterday
+days-ahead = { $days } days later
+days-behind = { $days } days earlier
+day-date = { $weekday } { $day } { $month } { $year }
+
+statistics = Statistics
+statistics-week = Last 7 days
+statistics-month = Last 30 days
+statistics-focus = Focus
+statistics-interruptions = Interruptions
+statistics-days = Days
+statistics-tasks = Tasks
+statistics-focus-blocks = Focus blocks
+statistics-focus-time = Focus time
+statistics-break-time = Break time
+statistics-rating = Average rating
+statistics-interruptions-per-block = Per focus block
+
+mode-pomodoro = Pomodoro
+mode-flowtime = Flowtime
+flowtime-take-break = Take a break
+flowtime-break-ratio = Flowtime break length
+
+daily-goal = Daily goal
+goal-pomodoros = pomodoros
+daily-goal-amount = Focus each day
+daily-goal-amount-des = Set to 0 to have no goal
+daily-goal-reached = Daily goal reached
+daily-goal-reached-des = You reached today's goal of { $goal }.
+daily-summary = End-of-day summary
+daily-summary-time = Summary time
+daily-summary-des = { $blocks } focus blocks, { $focus } of focus and { $breaks } of breaks today.
+daily-summary-tasks = Tasks: { $tasks }
+
+cancel = Cancel
+interruption-log = Log an interruption
+interruption-reason = What interrupted you?
+interruption-note = Note (optional)
+interruption-internal = Internal
+interruption-external = External
+interruptions-count = Interruptions: { $count }
+interruptions-summary = Interruptions: { $internal } internal, { $external } external
+
+save = Save
+history-search = Search notes and tasks
+reflection-prompt = Ask for a note and a rating after each focus block
+reflection-title = How did this focus block go?
+reflection-note = What did you get done?
+reflection-rating = Focus
+
+delete = Delete
+entry-add = Add a block done away from the computer
+entry-edit = Edit entry
+entry-date = Day
+entry-duration = Duration
+entry-end = Ended at
+
+history-show-breaks = Show breaks
+history-show-more = Show older days
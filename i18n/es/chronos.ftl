@@ -26,3 +26,208 @@ pomodoro-paused = Pomodoro pausado
 pomodoro-paused-des = Tu sesión de pomodoro ha sido pausada
 pomodoro-stopped = Pomodoro detenido
 pomodoro-stopped-des = El pomodoro se ha detenido y reiniciado
+
+task-placeholder = ¿En qué estás trabajando?
+
+// hooks
+hooks = Hooks
+hook-placeholder = Ruta a un ejecutable
+hook-focus-start = Concentración iniciada
+hook-focus-end = Concentración terminada
+hook-break-start = Descanso iniciado
+hook-break-end = Descanso terminado
+hook-paused = Temporizador pausado
+hook-reset = Temporizador reiniciado
+hook-timeout = Tiempo límite de los hooks
+seconds = segundos
+
+// webhooks
+webhooks = Webhooks
+webhook-placeholder = http://localhost:8080/chronos
+add-webhook = Añadir webhook
+webhook-timeout = Tiempo límite de la petición
+webhook-retries = Reintentos
+
+// bloques de concentración
+focus-blocks = Durante la concentración
+do-not-disturb = Activar No molestar
+inhibit-sleep = Mantener la pantalla encendida y evitar la suspensión
+auto-pause-on-lock = Pausar cuando se bloquee la pantalla o se suspenda el sistema
+auto-pause-on-idle = Pausar cuando esté inactivo
+
+// ausencia
+away-returned = Bienvenido de nuevo
+away-returned-des = Tu bloque de concentración se pausó mientras estuviste fuera { $duration }.
+away-resume = Reanudar
+away-count = Contar el tiempo
+away-discard = Descartar bloque
+
+// sonidos
+sounds = Sonidos
+activate-sounds = Reproducir un sonido al terminar cada fase
+sound-focus-end = Fin de la concentración
+sound-break-end = Fin del descanso
+sound-default = Sonido predeterminado, o ruta a un archivo de audio
+sound-preview = Escuchar
+sound-volume = Volumen
+ambience = Sonido de fondo durante la concentración
+ambience-off = Ninguno
+ambience-tick = Tictac de reloj
+ambience-white-noise = Ruido blanco
+ambience-brown-noise = Ruido marrón
+ambience-volume = Volumen del sonido de fondo
+
+// descansos
+breaks = Descansos
+break-overlay = Cubrir la pantalla durante los descansos
+break-postpone-minutes = Posponer un descanso
+break-postpone-limit = Aplazamientos permitidos por descanso
+break-time = Hora de descansar
+break-skip = Saltar descanso
+break-postpone = Posponer { $minutes } min (quedan { $left })
+break-suggestion-stretch = Levántate y estira la espalda y los hombros.
+break-suggestion-water = Bebe un vaso de agua.
+break-suggestion-eyes = Mira algo a seis metros durante 20 segundos.
+break-suggestion-walk = Da un paseo corto.
+break-suggestion-breathe = Respira lenta y profundamente unas cuantas veces.
+break-suggestion-window = Abre una ventana y toma aire fresco.
+
+compact-mode = Modo compacto
+compact-mode-leave = Salir del modo compacto
+
+// ventana
+window = Ventana
+compact-hide-header = Ocultar la barra de título en modo compacto
+
+// atajos
+shortcuts = Atajos de teclado
+shortcut-placeholder = Desactivado
+shortcut-invalid = Usa una combinación como Ctrl+Shift+S
+shortcut-taken = Ya la usa otro atajo
+shortcut-page = Mostrar { $page }
+start-pause = Iniciar o pausar
+skip = Saltar
+reset = Reiniciar
+
+title-countdown = Mostrar el tiempo restante en el título de la ventana
+
+// fases
+phase-focus = Concentración
+phase-short-break = Descanso corto
+phase-long-break = Descanso largo
+state-stopped = Listo
+state-running = En curso
+state-paused = En pausa
+
+// cronómetro
+stopwatch = Cronómetro
+stopwatch-lap = Vuelta
+stopwatch-laps = Vueltas
+stopwatch-lap-number = Vuelta { $number }
+copy = Copiar
+
+// temporizadores
+timers = Temporizadores
+timer-name = Nombre, como Té o Reunión
+add-timer = Añadir temporizador
+timer-finished = Se acabó el tiempo
+
+// alarmas
+alarms = Alarmas
+alarm = Alarma
+alarm-label = Etiqueta
+add-alarm = Añadir alarma
+alarm-snooze = Posponer
+alarm-dismiss = Descartar
+alarm-snooze-minutes = Posponer durante
+alarm-once = Una vez
+alarm-every-day = Todos los días
+weekday-mon = Lun
+weekday-tue = Mar
+weekday-wed = Mié
+weekday-thu = Jue
+weekday-fri = Vie
+weekday-sat = Sáb
+weekday-sun = Dom
+
+month-jan = ene
+month-feb = feb
+month-mar = mar
+month-apr = abr
+month-may = may
+month-jun = jun
+month-jul = jul
+month-aug = ago
+month-sep = sept
+month-oct = oct
+month-nov = nov
+month-dec = dic
+
+// reloj mundial
+world-clock = Reloj mundial
+world-clock-local = Hora local
+world-clock-search = Añadir una ciudad o zona horaria
+world-clock-unavailable = Zona horaria no encontrada
+world-clock-planner = Reunión a las
+working-hours = Horario laboral
+today = Hoy
+tomorrow = Mañana
+yesterday = Ayer
+days-ahead = { $days } días después
+days-behind = { $days } días antes
+day-date = { $weekday }, { $day } { $month } { $year }
+
+statistics = Estadísticas
+statistics-week = Últimos 7 días
+statistics-month = Últimos 30 días
+statistics-focus = Concentración
+statistics-interruptions = Interrupciones
+statistics-days = Días
+statistics-tasks = Tareas
+statistics-focus-blocks = Bloques de concentración
+statistics-focus-time = Tiempo de concentración
+statistics-break-time = Tiempo de descanso
+statistics-rating = Valoración media
+statistics-interruptions-per-block = Por bloque de concentración
+
+mode-pomodoro = Pomodoro
+mode-flowtime = Flowtime
+flowtime-take-break = Tomar un descanso
+flowtime-break-ratio = Duración del descanso Flowtime
+
+daily-goal = Objetivo diario
+goal-pomodoros = pomodoros
+daily-goal-amount = Concentración cada día
+daily-goal-amount-des = Pon 0 para no tener objetivo
+daily-goal-reached = Objetivo diario alcanzado
+daily-goal-reached-des = Has alcanzado el objetivo de hoy de { $goal }.
+daily-summary = Resumen del día
+daily-summary-time = Hora del resumen
+daily-summary-des = Hoy { $blocks } bloques de concentración, { $focus } de concentración y { $breaks } de descansos.
+daily-summary-tasks = Tareas: { $tasks }
+
+cancel = Cancelar
+interruption-log = Registrar una interrupción
+interruption-reason = ¿Qué te interrumpió?
+interruption-note = Nota (opcional)
+interruption-internal = Interna
+interruption-external = Externa
+interruptions-count = Interrupciones: { $count }
+interruptions-summary = Interrupciones: { $internal } internas, { $external } externas
+
+save = Guardar
+history-search = Buscar notas y tareas
+reflection-prompt = Pedir una nota y una valoración tras cada bloque de concentración
+reflection-title = ¿Cómo fue este bloque de concentración?
+reflection-note = ¿Qué has hecho?
+reflection-rating = Concentración
+
+delete = Eliminar
+entry-add = Añadir un bloque hecho lejos del ordenador
+entry-edit = Editar entrada
+entry-date = Día
+entry-duration = Duración
+entry-end = Terminó a las
+
+history-show-breaks = Mostrar descansos
+history-show-more = Mostrar días anteriores
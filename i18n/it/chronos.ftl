@@ -27,3 +27,208 @@ pomodoro-paused = Pomodoro in pausa
 pomodoro-paused-des = La tua sessione pomodoro è stata messa in pausa
 pomodoro-stopped = Pomodoro fermato
 pomodoro-stopped-des = Il Pomodoro è stato fermato e reimpostato
+
+task-placeholder = A cosa stai lavorando?
+
+// hook
+hooks = Hook
+hook-placeholder = Percorso di un eseguibile
+hook-focus-start = Concentrazione iniziata
+hook-focus-end = Concentrazione terminata
+hook-break-start = Pausa iniziata
+hook-break-end = Pausa terminata
+hook-paused = Timer in pausa
+hook-reset = Timer reimpostato
+hook-timeout = Timeout degli hook
+seconds = secondi
+
+// webhook
+webhooks = Webhook
+webhook-placeholder = http://localhost:8080/chronos
+add-webhook = Aggiungi webhook
+webhook-timeout = Timeout della richiesta
+webhook-retries = Tentativi
+
+// blocchi di concentrazione
+focus-blocks = Durante la concentrazione
+do-not-disturb = Attiva Non disturbare
+inhibit-sleep = Mantieni lo schermo acceso e impedisci la sospensione
+auto-pause-on-lock = Metti in pausa quando lo schermo si blocca o il sistema va in sospensione
+auto-pause-on-idle = Metti in pausa quando sono inattivo
+
+// assenza
+away-returned = Bentornato
+away-returned-des = Il blocco di concentrazione è stato messo in pausa mentre eri via per { $duration }.
+away-resume = Riprendi
+away-count = Conta il tempo
+away-discard = Scarta il blocco
+
+// suoni
+sounds = Suoni
+activate-sounds = Riproduci un suono alla fine di ogni fase
+sound-focus-end = Fine della concentrazione
+sound-break-end = Fine della pausa
+sound-default = Suono predefinito, o percorso di un file audio
+sound-preview = Anteprima
+sound-volume = Volume
+ambience = Suono di sottofondo durante la concentrazione
+ambience-off = Nessuno
+ambience-tick = Ticchettio
+ambience-white-noise = Rumore bianco
+ambience-brown-noise = Rumore marrone
+ambience-volume = Volume del sottofondo
+
+// pause
+breaks = Pause
+break-overlay = Copri lo schermo durante le pause
+break-postpone-minutes = Rimanda una pausa di
+break-postpone-limit = Rinvii consentiti per pausa
+break-time = È ora di una pausa
+break-skip = Salta la pausa
+break-postpone = Rimanda di { $minutes } min (ne restano { $left })
+break-suggestion-stretch = Alzati e allunga la schiena e le spalle.
+break-suggestion-water = Bevi un bicchiere d'acqua.
+break-suggestion-eyes = Guarda qualcosa a sei metri di distanza per 20 secondi.
+break-suggestion-walk = Fai una breve passeggiata.
+break-suggestion-breathe = Fai qualche respiro lento e profondo.
+break-suggestion-window = Apri una finestra e prendi un po' d'aria.
+
+compact-mode = Modalità compatta
+compact-mode-leave = Esci dalla modalità compatta
+
+// finestra
+window = Finestra
+compact-hide-header = Nascondi la barra del titolo in modalità compatta
+
+// scorciatoie
+shortcuts = Scorciatoie da tastiera
+shortcut-placeholder = Disattivata
+shortcut-invalid = Usa una combinazione come Ctrl+Shift+S
+shortcut-taken = Già usata da un'altra scorciatoia
+shortcut-page = Mostra { $page }
+start-pause = Avvia o metti in pausa
+skip = Salta
+reset = Azzera
+
+title-countdown = Mostra il tempo rimanente nel titolo della finestra
+
+// fasi
+phase-focus = Concentrazione
+phase-short-break = Pausa breve
+phase-long-break = Pausa lunga
+state-stopped = Pronto
+state-running = In corso
+state-paused = In pausa
+
+// cronometro
+stopwatch = Cronometro
+stopwatch-lap = Giro
+stopwatch-laps = Giri
+stopwatch-lap-number = Giro { $number }
+copy = Copia
+
+// timer
+timers = Timer
+timer-name = Nome, come Tè o Riunione
+add-timer = Aggiungi timer
+timer-finished = Il tempo è scaduto
+
+// sveglie
+alarms = Sveglie
+alarm = Sveglia
+alarm-label = Etichetta
+add-alarm = Aggiungi sveglia
+alarm-snooze = Posticipa
+alarm-dismiss = Ignora
+alarm-snooze-minutes = Posticipa di
+alarm-once = Una volta
+alarm-every-day = Ogni giorno
+weekday-mon = Lun
+weekday-tue = Mar
+weekday-wed = Mer
+weekday-thu = Gio
+weekday-fri = Ven
+weekday-sat = Sab
+weekday-sun = Dom
+
+month-jan = gen
+month-feb = feb
+month-mar = mar
+month-apr = apr
+month-may = mag
+month-jun = giu
+month-jul = lug
+month-aug = ago
+month-sep = set
+month-oct = ott
+month-nov = nov
+month-dec = dic
+
+// orologio mondiale
+world-clock = Orologio mondiale
+world-clock-local = Ora locale
+world-clock-search = Aggiungi una città o un fuso orario
+world-clock-unavailable = Fuso orario non trovato
+world-clock-planner = Riunione alle
+working-hours = Orario di lavoro
+today = Oggi
+tomorrow = Domani
+yesterday = Ieri
+days-ahead = { $days } giorni dopo
+days-behind = { $days } giorni prima
+day-date = { $weekday } { $day } { $month } { $year }
+
+statistics = Statistiche
+statistics-week = Ultimi 7 giorni
+statistics-month = Ultimi 30 giorni
+statistics-focus = Concentrazione
+statistics-interruptions = Interruzioni
+statistics-days = Giorni
+statistics-tasks = Attività
+statistics-focus-blocks = Blocchi di concentrazione
+statistics-focus-time = Tempo di concentrazione
+statistics-break-time = Tempo di pausa
+statistics-rating = Valutazione media
+statistics-interruptions-per-block = Per blocco di concentrazione
+
+mode-pomodoro = Pomodoro
+mode-flowtime = Flowtime
+flowtime-take-break = Fai una pausa
+flowtime-break-ratio = Durata della pausa Flowtime
+
+daily-goal = Obiettivo giornaliero
+goal-pomodoros = pomodori
+daily-goal-amount = Concentrazione ogni giorno
+daily-goal-amount-des = Imposta 0 per non avere un obiettivo
+daily-goal-reached = Obiettivo giornaliero raggiunto
+daily-goal-reached-des = Hai raggiunto l'obiettivo di oggi di { $goal }.
+daily-summary = Riepilogo di fine giornata
+daily-summary-time = Ora del riepilogo
+daily-summary-des = Oggi { $blocks } blocchi di concentrazione, { $focus } di concentrazione e { $breaks } di pause.
+daily-summary-tasks = Attività: { $tasks }
+
+cancel = Annulla
+interruption-log = Registra un'interruzione
+interruption-reason = Cosa ti ha interrotto?
+interruption-note = Nota (facoltativa)
+interruption-internal = Interna
+interruption-external = Esterna
+interruptions-count = Interruzioni: { $count }
+interruptions-summary = Interruzioni: { $internal } interne, { $external } esterne
+
+save = Salva
+history-search = Cerca note e attività
+reflection-prompt = Chiedi una nota e un voto dopo ogni blocco di concentrazione
+reflection-title = Com'è andato questo blocco di concentrazione?
+reflection-note = Cosa hai fatto?
+reflection-rating = Concentrazione
+
+delete = Elimina
+entry-add = Aggiungi un blocco svolto lontano dal computer
+entry-edit = Modifica voce
+entry-date = Giorno
+entry-duration = Durata
+entry-end = Terminato alle
+
+history-show-breaks = Mostra le pause
+history-show-more = Mostra i giorni precedenti
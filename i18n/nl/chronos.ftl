@@ -26,3 +26,208 @@ pomodoro-paused = Pomodoro gepauzeerd
 pomodoro-paused-des = Uw pomodoro-session is gepauzeerd
 pomodoro-stopped = Pomodoro gestopt
 pomodoro-stopped-des = Pomodoro is gestopt en gereset
+
+task-placeholder = Waar werk je aan?
+
+// hooks
+hooks = Hooks
+hook-placeholder = Pad naar een uitvoerbaar bestand
+hook-focus-start = Focus gestart
+hook-focus-end = Focus beëindigd
+hook-break-start = Pauze gestart
+hook-break-end = Pauze beëindigd
+hook-paused = Timer gepauzeerd
+hook-reset = Timer gereset
+hook-timeout = Time-out voor hooks
+seconds = seconden
+
+// webhooks
+webhooks = Webhooks
+webhook-placeholder = http://localhost:8080/chronos
+add-webhook = Webhook toevoegen
+webhook-timeout = Time-out van verzoek
+webhook-retries = Herhaalpogingen
+
+// focusblokken
+focus-blocks = Tijdens focusblokken
+do-not-disturb = Niet storen inschakelen
+inhibit-sleep = Scherm aan houden en slaapstand voorkomen
+auto-pause-on-lock = Pauzeren wanneer het scherm vergrendelt of het systeem in slaapstand gaat
+auto-pause-on-idle = Pauzeren wanneer ik inactief ben
+
+// afwezig
+away-returned = Welkom terug
+away-returned-des = Je focusblok is gepauzeerd terwijl je { $duration } weg was.
+away-resume = Hervatten
+away-count = Tijd meetellen
+away-discard = Blok verwerpen
+
+// geluiden
+sounds = Geluiden
+activate-sounds = Een geluid afspelen wanneer een fase eindigt
+sound-focus-end = Einde van focus
+sound-break-end = Einde van pauze
+sound-default = Standaardgeluid, of pad naar een geluidsbestand
+sound-preview = Voorbeeld
+sound-volume = Volume
+ambience = Achtergrondgeluid tijdens focus
+ambience-off = Geen
+ambience-tick = Tikkende klok
+ambience-white-noise = Witte ruis
+ambience-brown-noise = Bruine ruis
+ambience-volume = Volume van achtergrondgeluid
+
+// pauzes
+breaks = Pauzes
+break-overlay = Scherm bedekken tijdens pauzes
+break-postpone-minutes = Pauze uitstellen met
+break-postpone-limit = Toegestane uitstellen per pauze
+break-time = Tijd voor een pauze
+break-skip = Pauze overslaan
+break-postpone = { $minutes } min uitstellen (nog { $left })
+break-suggestion-stretch = Sta op en rek je rug en schouders.
+break-suggestion-water = Drink een glas water.
+break-suggestion-eyes = Kijk 20 seconden naar iets op zes meter afstand.
+break-suggestion-walk = Maak een korte wandeling.
+break-suggestion-breathe = Adem een paar keer langzaam en diep.
+break-suggestion-window = Open een raam en haal frisse lucht.
+
+compact-mode = Compacte modus
+compact-mode-leave = Compacte modus verlaten
+
+// venster
+window = Venster
+compact-hide-header = Kopbalk verbergen in compacte modus
+
+// sneltoetsen
+shortcuts = Sneltoetsen
+shortcut-placeholder = Uitgeschakeld
+shortcut-invalid = Gebruik een combinatie zoals Ctrl+Shift+S
+shortcut-taken = Al in gebruik door een andere sneltoets
+shortcut-page = { $page } tonen
+start-pause = Starten of pauzeren
+skip = Overslaan
+reset = Herstellen
+
+title-countdown = Resterende tijd in de venstertitel tonen
+
+// fasen
+phase-focus = Focus
+phase-short-break = Korte pauze
+phase-long-break = Lange pauze
+state-stopped = Klaar
+state-running = Loopt
+state-paused = Gepauzeerd
+
+// stopwatch
+stopwatch = Stopwatch
+stopwatch-lap = Ronde
+stopwatch-laps = Rondes
+stopwatch-lap-number = Ronde { $number }
+copy = Kopiëren
+
+// timers
+timers = Timers
+timer-name = Naam, zoals Thee of Standup
+add-timer = Timer toevoegen
+timer-finished = De tijd is om
+
+// wekkers
+alarms = Wekkers
+alarm = Wekker
+alarm-label = Label
+add-alarm = Wekker toevoegen
+alarm-snooze = Sluimeren
+alarm-dismiss = Negeren
+alarm-snooze-minutes = Sluimeren voor
+alarm-once = Eenmalig
+alarm-every-day = Elke dag
+weekday-mon = Ma
+weekday-tue = Di
+weekday-wed = Wo
+weekday-thu = Do
+weekday-fri = Vr
+weekday-sat = Za
+weekday-sun = Zo
+
+month-jan = jan
+month-feb = feb
+month-mar = mrt
+month-apr = apr
+month-may = mei
+month-jun = jun
+month-jul = jul
+month-aug = aug
+month-sep = sep
+month-oct = okt
+month-nov = nov
+month-dec = dec
+
+// wereldklok
+world-clock = Wereldklok
+world-clock-local = Lokale tijd
+world-clock-search = Stad of tijdzone toevoegen
+world-clock-unavailable = Tijdzone niet gevonden
+world-clock-planner = Vergadering om
+working-hours = Werktijden
+today = Vandaag
+tomorrow = Morgen
+yesterday = Gisteren
+days-ahead = { $days } dagen later
+days-behind = { $days } dagen eerder
+day-date = { $weekday } { $day } { $month } { $year }
+
+statistics = Statistieken
+statistics-week = Laatste 7 dagen
+statistics-month = Laatste 30 dagen
+statistics-focus = Focus
+statistics-interruptions = Onderbrekingen
+statistics-days = Dagen
+statistics-tasks = Taken
+statistics-focus-blocks = Focusblokken
+statistics-focus-time = Focustijd
+statistics-break-time = Pauzetijd
+statistics-rating = Gemiddelde beoordeling
+statistics-interruptions-per-block = Per focusblok
+
+mode-pomodoro = Pomodoro
+mode-flowtime = Flowtime
+flowtime-take-break = Neem een pauze
+flowtime-break-ratio = Lengte van de Flowtime-pauze
+
+daily-goal = Dagdoel
+goal-pomodoros = pomodoro's
+daily-goal-amount = Focus per dag
+daily-goal-amount-des = Zet op 0 om geen doel te hebben
+daily-goal-reached = Dagdoel bereikt
+daily-goal-reached-des = Je hebt het doel van vandaag van { $goal } bereikt.
+daily-summary = Dagoverzicht
+daily-summary-time = Tijd van het overzicht
+daily-summary-des = Vandaag { $blocks } focusblokken, { $focus } focus en { $breaks } pauze.
+daily-summary-tasks = Taken: { $tasks }
+
+cancel = Annuleren
+interruption-log = Onderbreking vastleggen
+interruption-reason = Wat onderbrak je?
+interruption-note = Notitie (optioneel)
+interruption-internal = Intern
+interruption-external = Extern
+interruptions-count = Onderbrekingen: { $count }
+interruptions-summary = Onderbrekingen: { $internal } intern, { $external } extern
+
+save = Opslaan
+history-search = Notities en taken zoeken
+reflection-prompt = Vraag na elk focusblok om een notitie en een beoordeling
+reflection-title = Hoe ging dit focusblok?
+reflection-note = Wat heb je gedaan?
+reflection-rating = Focus
+
+delete = Verwijderen
+entry-add = Een blok buiten de computer toevoegen
+entry-edit = Item bewerken
+entry-date = Dag
+entry-duration = Duur
+entry-end = Geëindigd om
+
+history-show-breaks = Pauzes tonen
+history-show-more = Oudere dagen tonen
@@ -24,3 +24,208 @@ pomodoro-paused = Pomodoro pausad
 pomodoro-paused-des = Din pomodoro-session har pausats
 pomodoro-stopped = Pomodoro stoppad
 pomodoro-stopped-des = Pomodoro har stoppats och återställts
+
+task-placeholder = Vad arbetar du med?
+
+// krokar
+hooks = Krokar
+hook-placeholder = Sökväg till en körbar fil
+hook-focus-start = Fokus startat
+hook-focus-end = Fokus avslutat
+hook-break-start = Paus startad
+hook-break-end = Paus avslutad
+hook-paused = Timer pausad
+hook-reset = Timer återställd
+hook-timeout = Tidsgräns för krokar
+seconds = sekunder
+
+// webhooks
+webhooks = Webhooks
+webhook-placeholder = http://localhost:8080/chronos
+add-webhook = Lägg till webhook
+webhook-timeout = Tidsgräns för begäran
+webhook-retries = Nya försök
+
+// fokusblock
+focus-blocks = Under fokusblock
+do-not-disturb = Aktivera Stör ej
+inhibit-sleep = Håll skärmen på och förhindra vänteläge
+auto-pause-on-lock = Pausa när skärmen låses eller systemet går i vänteläge
+auto-pause-on-idle = Pausa när jag är inaktiv
+
+// borta
+away-returned = Välkommen tillbaka
+away-returned-des = Ditt fokusblock pausades medan du var borta i { $duration }.
+away-resume = Återuppta
+away-count = Räkna tiden
+away-discard = Kasta blocket
+
+// ljud
+sounds = Ljud
+activate-sounds = Spela ett ljud när en fas slutar
+sound-focus-end = Slut på fokus
+sound-break-end = Slut på paus
+sound-default = Standardljud, eller sökväg till en ljudfil
+sound-preview = Förhandslyssna
+sound-volume = Volym
+ambience = Bakgrundsljud under fokus
+ambience-off = Inget
+ambience-tick = Tickande klocka
+ambience-white-noise = Vitt brus
+ambience-brown-noise = Brunt brus
+ambience-volume = Volym för bakgrundsljud
+
+// pauser
+breaks = Pauser
+break-overlay = Täck skärmen under pauser
+break-postpone-minutes = Skjut upp en paus med
+break-postpone-limit = Tillåtna uppskjutningar per paus
+break-time = Dags för en paus
+break-skip = Hoppa över pausen
+break-postpone = Skjut upp { $minutes } min ({ $left } kvar)
+break-suggestion-stretch = Res dig upp och sträck på rygg och axlar.
+break-suggestion-water = Drick ett glas vatten.
+break-suggestion-eyes = Titta på något sex meter bort i 20 sekunder.
+break-suggestion-walk = Ta en kort promenad.
+break-suggestion-breathe = Ta några långsamma, djupa andetag.
+break-suggestion-window = Öppna ett fönster och få lite frisk luft.
+
+compact-mode = Kompakt läge
+compact-mode-leave = Lämna kompakt läge
+
+// fönster
+window = Fönster
+compact-hide-header = Dölj rubrikraden i kompakt läge
+
+// kortkommandon
+shortcuts = Kortkommandon
+shortcut-placeholder = Inaktiverat
+shortcut-invalid = Använd en kombination som Ctrl+Shift+S
+shortcut-taken = Används redan av ett annat kortkommando
+shortcut-page = Visa { $page }
+start-pause = Starta eller pausa
+skip = Hoppa över
+reset = Återställ
+
+title-countdown = Visa återstående tid i fönstertiteln
+
+// faser
+phase-focus = Fokus
+phase-short-break = Kort paus
+phase-long-break = Lång paus
+state-stopped = Redo
+state-running = Pågår
+state-paused = Pausad
+
+// tidtagarur
+stopwatch = Tidtagarur
+stopwatch-lap = Varv
+stopwatch-laps = Varv
+stopwatch-lap-number = Varv { $number }
+copy = Kopiera
+
+// timrar
+timers = Timrar
+timer-name = Namn, till exempel Te eller Standup
+add-timer = Lägg till timer
+timer-finished = Tiden är ute
+
+// alarm
+alarms = Alarm
+alarm = Alarm
+alarm-label = Etikett
+add-alarm = Lägg till alarm
+alarm-snooze = Snooza
+alarm-dismiss = Avfärda
+alarm-snooze-minutes = Snooza i
+alarm-once = En gång
+alarm-every-day = Varje dag
+weekday-mon = Mån
+weekday-tue = Tis
+weekday-wed = Ons
+weekday-thu = Tor
+weekday-fri = Fre
+weekday-sat = Lör
+weekday-sun = Sön
+
+month-jan = jan.
+month-feb = feb.
+month-mar = mars
+month-apr = apr.
+month-may = maj
+month-jun = juni
+month-jul = juli
+month-aug = aug.
+month-sep = sep.
+month-oct = okt.
+month-nov = nov.
+month-dec = dec.
+
+// världsklocka
+world-clock = Världsklocka
+world-clock-local = Lokal tid
+world-clock-search = Lägg till en stad eller tidszon
+world-clock-unavailable = Tidszonen hittades inte
+world-clock-planner = Möte klockan
+working-hours = Arbetstid
+today = Idag
+tomorrow = I morgon
+yesterday = Igår
+days-ahead = { $days } dagar senare
+days-behind = { $days } dagar tidigare
+day-date = { $weekday } { $day } { $month } { $year }
+
+statistics = Statistik
+statistics-week = Senaste 7 dagarna
+statistics-month = Senaste 30 dagarna
+statistics-focus = Fokus
+statistics-interruptions = Avbrott
+statistics-days = Dagar
+statistics-tasks = Uppgifter
+statistics-focus-blocks = Fokusblock
+statistics-focus-time = Fokustid
+statistics-break-time = Paustid
+statistics-rating = Genomsnittligt betyg
+statistics-interruptions-per-block = Per fokusblock
+
+mode-pomodoro = Pomodoro
+mode-flowtime = Flowtime
+flowtime-take-break = Ta en paus
+flowtime-break-ratio = Längd på Flowtime-paus
+
+daily-goal = Dagligt mål
+goal-pomodoros = pomodoros
+daily-goal-amount = Fokus varje dag
+daily-goal-amount-des = Sätt till 0 för inget mål
+daily-goal-reached = Dagligt mål uppnått
+daily-goal-reached-des = Du har nått dagens mål på { $goal }.
+daily-summary = Dagssammanfattning
+daily-summary-time = Tid för sammanfattning
+daily-summary-des = I dag { $blocks } fokusblock, { $focus } fokus och { $breaks } pauser.
+daily-summary-tasks = Uppgifter: { $tasks }
+
+cancel = Avbryt
+interruption-log = Logga ett avbrott
+interruption-reason = Vad avbröt dig?
+interruption-note = Anteckning (valfri)
+interruption-internal = Internt
+interruption-external = Externt
+interruptions-count = Avbrott: { $count }
+interruptions-summary = Avbrott: { $internal } interna, { $external } externa
+
+save = Spara
+history-search = Sök anteckningar och uppgifter
+reflection-prompt = Be om en anteckning och ett betyg efter varje fokusblock
+reflection-title = Hur gick det här fokusblocket?
+reflection-note = Vad fick du gjort?
+reflection-rating = Fokus
+
+delete = Ta bort
+entry-add = Lägg till ett block utfört borta från datorn
+entry-edit = Redigera post
+entry-date = Dag
+entry-duration = Längd
+entry-end = Slutade
+
+history-show-breaks = Visa pauser
+history-show-more = Visa äldre dagar
@@ -1,14 +1,27 @@
 // SPDX-License-Identifier: {{LICENSE}}
 
 use crate::config::Config;
+use crate::core::alarms;
+use crate::core::ambience::AmbiencePlayer;
+use crate::core::cli::{Flags, TimerAction};
+use crate::core::dnd::DoNotDisturb;
+use crate::core::hooks;
+use crate::core::inhibit::Inhibitor;
 use crate::core::nav::NavPage;
+use crate::core::presence::{self, AwayReason, PresenceEvent};
+use crate::core::shortcuts::{self, Shortcut};
+use crate::core::sound::{Sound, SoundPlayer};
+use crate::core::timer::{format_duration, TimerEvent, TimerEventKind};
+use crate::core::webhooks::Webhooks;
 use crate::{fl, pages};
 use cosmic::app::context_drawer;
 use cosmic::app::{Core, Task};
 use cosmic::cosmic_config::{self, CosmicConfigEntry};
-use cosmic::iced::time;
-use cosmic::iced::{Alignment, Length, Subscription};
+use cosmic::iced::keyboard::{Key, Modifiers};
+use cosmic::iced::{event, keyboard, time, window, Event};
+use cosmic::iced::{Alignment, Length, Point, Size, Subscription};
 use cosmic::widget::about::About;
+use cosmic::widget::menu::action::MenuAction as _;
 use cosmic::widget::{self, menu, nav_bar};
 use cosmic::{cosmic_theme, theme, Application, ApplicationExt, Element};
 use std::collections::HashMap;
@@ -35,8 +48,33 @@ pub struct AppModel {
 
     pub pomodoro: pages::pomodoro::Pomodoro,
     pub settings: pages::settings::Settings,
+    pub stopwatch: pages::stopwatch::Stopwatch,
+    pub timers: pages::timers::Timers,
+    pub alarms: pages::alarms::Alarms,
+    pub world_clock: pages::world_clock::WorldClock,
+    pub statistics: pages::statistics::Statistics,
 
     pomodoro_tick_state: PomodoroTickState,
+    /// Do-not-disturb mode held during focus blocks.
+    dnd: DoNotDisturb,
+    /// Idle, screen lock and suspend inhibitor held during focus blocks.
+    inhibitor: Inhibitor,
+    /// Queue delivering timer events to the webhook URLs in order.
+    webhooks: Webhooks,
+    sounds: SoundPlayer,
+    /// Background sound played during focus blocks.
+    ambience: AmbiencePlayer,
+    /// Full screen window shown during breaks, when open.
+    break_window: Option<window::Id>,
+    /// Whether the main window only shows the running timer.
+    compact: bool,
+    /// Size of the main window, used to restore it when leaving compact mode.
+    window_size: Size,
+    normal_size: Size,
+    /// Size and position reported by the main window since it turned compact, the
+    /// compositor may not report them at all.
+    compact_size: Option<Size>,
+    compact_position: Option<Point>,
 }
 
 /// Messages emitted by the application and its widgets.
@@ -51,10 +89,29 @@ pub enum Message {
     // pages
     Pomodoro(pages::pomodoro::PomodoroMessage),
     Settings(pages::settings::SettingsMessage),
+    Stopwatch(pages::stopwatch::StopwatchMessage),
+    Timers(pages::timers::TimersMessage),
+    Alarms(pages::alarms::AlarmsMessage),
+    WorldClock(pages::world_clock::WorldClockMessage),
+    Statistics(pages::statistics::StatisticsMessage),
 
     PomodoroTick,
     StartPomodoroTimer,
     PausePomodoroTimer,
+    TimerEvent(TimerEvent),
+    Presence(PresenceEvent),
+    PreviewSound(Sound, String, u32),
+    /// A named countdown ended, with its sound file.
+    CountdownFinished(String),
+    /// An alarm rang, with its sound file.
+    AlarmRang(String),
+    BreakWindowClosed(window::Id),
+    Key(Modifiers, Key),
+    Shortcut(Shortcut),
+    ToggleCompact,
+    WindowResized(window::Id, Size),
+    WindowMoved(window::Id, Point),
+    DragWindow,
 }
 
 #[derive(Default)]
@@ -72,7 +129,7 @@ impl Application for AppModel {
     type Executor = cosmic::executor::Default;
 
     /// Data that your application receives to its init method.
-    type Flags = ();
+    type Flags = Flags;
 
     /// Messages which the application and its widgets will emit.
     type Message = Message;
@@ -89,7 +146,7 @@ impl Application for AppModel {
     }
 
     /// Initializes the application with any given flags and startup commands.
-    fn init(core: Core, _flags: Self::Flags) -> (Self, Task<Self::Message>) {
+    fn init(core: Core, flags: Self::Flags) -> (Self, Task<Self::Message>) {
         // Create a nav bar with three page items.
         let mut nav = nav_bar::Model::default();
 
@@ -143,24 +200,84 @@ impl Application for AppModel {
             about,
             pomodoro: pages::pomodoro::Pomodoro::default(),
             settings: pages::settings::Settings::default(),
+            stopwatch: pages::stopwatch::Stopwatch::default(),
+            timers: pages::timers::Timers::default(),
+            alarms: pages::alarms::Alarms::default(),
+            world_clock: pages::world_clock::WorldClock::default(),
+            statistics: pages::statistics::Statistics::default(),
             pomodoro_tick_state: PomodoroTickState::Idle,
+            dnd: DoNotDisturb::recover(),
+            inhibitor: Inhibitor::new(),
+            webhooks: Webhooks::new(),
+            sounds: SoundPlayer::default(),
+            ambience: AmbiencePlayer::default(),
+            break_window: None,
+            compact: false,
+            window_size: Size::new(500., 800.),
+            normal_size: Size::new(500., 800.),
+            compact_size: None,
+            compact_position: None,
         };
 
+        app.key_binds = key_binds(&app.config);
+        app.save_status();
+
         // Create a startup command that sets the window title.
-        let command = app.update_title();
+        let mut commands = vec![app.update_title()];
+        // Countdowns that ended while Chronos was closed
+        commands.push(app.update(Message::Timers(pages::timers::TimersMessage::Tick)));
+
+        if let Some(action) = flags.action {
+            commands.push(app.handle_timer_action(action));
+        }
 
-        (app, command)
+        (app, Task::batch(commands))
     }
 
     /// Elements to pack at the start of the header bar.
     fn header_start(&self) -> Vec<Element<Self::Message>> {
-        let menu_bar = menu::bar(vec![menu::Tree::with_children(
-            menu::root(fl!("view")),
-            menu::items(
-                &self.key_binds,
-                vec![menu::Item::Button(fl!("about"), None, MenuAction::About)],
+        let menu_bar = menu::bar(vec![
+            menu::Tree::with_children(
+                menu::root(fl!("timer")),
+                menu::items(
+                    &self.key_binds,
+                    vec![
+                        menu::Item::Button(
+                            fl!("start-pause"),
+                            None,
+                            MenuAction::Shortcut(Shortcut::StartPause),
+                        ),
+                        menu::Item::Button(fl!("skip"), None, MenuAction::Shortcut(Shortcut::Skip)),
+                        menu::Item::Button(
+                            fl!("reset"),
+                            None,
+                            MenuAction::Shortcut(Shortcut::Reset),
+                        ),
+                    ],
+                ),
             ),
-        )]);
+            menu::Tree::with_children(
+                menu::root(fl!("view")),
+                menu::items(
+                    &self.key_binds,
+                    vec![
+                        menu::Item::CheckBox(
+                            fl!("compact-mode"),
+                            None,
+                            self.compact,
+                            MenuAction::Shortcut(Shortcut::CompactMode),
+                        ),
+                        menu::Item::Button(
+                            fl!("settings"),
+                            None,
+                            MenuAction::Shortcut(Shortcut::Settings),
+                        ),
+                        menu::Item::Divider,
+                        menu::Item::Button(fl!("about"), None, MenuAction::About),
+                    ],
+                ),
+            ),
+        ]);
 
         vec![menu_bar.into()]
     }
@@ -192,6 +309,10 @@ impl Application for AppModel {
         let entity = self.nav.active();
         let nav_page = self.nav.data::<NavPage>(entity).unwrap_or_default();
 
+        if self.compact {
+            return self.compact_view();
+        }
+
         widget::column::with_children(vec![nav_page.view(self)])
             .padding(spacing.space_xs)
             .width(Length::Fill)
@@ -200,6 +321,23 @@ impl Application for AppModel {
             .into()
     }
 
+    /// Describes the interface of windows other than the main one.
+    fn view_window(&self, id: window::Id) -> Element<Self::Message> {
+        if self.break_window == Some(id) {
+            return self.pomodoro.break_view().map(Message::Pomodoro);
+        }
+        widget::Space::new(Length::Fill, Length::Fill).into()
+    }
+
+    /// Remembers the compact window when the app is closed in compact mode, and forgets
+    /// about the break window when it gets closed from outside the app.
+    fn on_close_requested(&self, id: window::Id) -> Option<Self::Message> {
+        if self.compact && self.core.main_window_id() == Some(id) {
+            self.save_compact_geometry();
+        }
+        (self.break_window == Some(id)).then_some(Message::BreakWindowClosed(id))
+    }
+
     /// Register subscriptions for this application.
     ///
     /// Subscriptions are long-running async tasks running in the background which
@@ -212,7 +350,74 @@ impl Application for AppModel {
                 time::every(Duration::from_secs(1)).map(|_instant| Message::PomodoroTick)
             }
         };
-        Subscription::batch(vec![tick])
+        let config = Config::subscription().map(|update| Message::UpdateConfig(update.config));
+        let presence = if self.config.auto_pause_on_lock || self.config.auto_pause_on_idle {
+            presence::subscription().map(Message::Presence)
+        } else {
+            Subscription::none()
+        };
+        let events = event::listen_with(|event, status, id| match event {
+            Event::Keyboard(keyboard::Event::KeyPressed { key, modifiers, .. }) => match status {
+                event::Status::Ignored => Some(Message::Key(modifiers, key)),
+                event::Status::Captured => None,
+            },
+            Event::Window(window::Event::Resized(size)) => Some(Message::WindowResized(id, size)),
+            Event::Window(window::Event::Moved(point)) => Some(Message::WindowMoved(id, point)),
+            _ => None,
+        });
+        // Only redraw the stopwatch's milliseconds while they can be seen
+        let stopwatch = if self.stopwatch.is_running()
+            && !self.compact
+            && self.nav.active_data::<NavPage>() == Some(&NavPage::Stopwatch)
+        {
+            time::every(Duration::from_millis(47))
+                .map(|_instant| Message::Stopwatch(pages::stopwatch::StopwatchMessage::Tick))
+        } else {
+            Subscription::none()
+        };
+        let timers = if self.timers.is_running() {
+            time::every(Duration::from_secs(1))
+                .map(|_instant| Message::Timers(pages::timers::TimersMessage::Tick))
+        } else {
+            Subscription::none()
+        };
+        let alarm = match self.alarms.next_due() {
+            Some(at) => alarms::subscription("alarm", at)
+                .map(|()| Message::Alarms(pages::alarms::AlarmsMessage::Due)),
+            None => Subscription::none(),
+        };
+        let daily_summary = match self
+            .config
+            .daily_summary
+            .then(|| {
+                self.pomodoro
+                    .next_summary_at(self.config.daily_summary_time)
+            })
+            .flatten()
+        {
+            Some(at) => alarms::subscription("daily-summary", at)
+                .map(|()| Message::Pomodoro(pages::pomodoro::PomodoroMessage::DailySummary)),
+            None => Subscription::none(),
+        };
+        let world_clock = if !self.compact
+            && self.nav.active_data::<NavPage>() == Some(&NavPage::WorldClock)
+        {
+            time::every(Duration::from_secs(1))
+                .map(|_instant| Message::WorldClock(pages::world_clock::WorldClockMessage::Tick))
+        } else {
+            Subscription::none()
+        };
+        Subscription::batch(vec![
+            tick,
+            config,
+            presence,
+            events,
+            stopwatch,
+            timers,
+            alarm,
+            daily_summary,
+            world_clock,
+        ])
     }
 
     /// Handles messages emitted by the application and its widgets.
@@ -235,13 +440,65 @@ impl Application for AppModel {
             }
 
             Message::UpdateConfig(config) => {
+                if config.shortcuts != self.config.shortcuts {
+                    self.key_binds = key_binds(&config);
+                }
+                if !config.do_not_disturb {
+                    self.dnd.restore();
+                }
+                if !config.inhibit_sleep {
+                    self.inhibitor.set(false);
+                }
+                if self.ambience.playing().is_some() {
+                    self.ambience.play(config.ambience, config.ambience_volume);
+                }
                 self.config = config;
+                commands.push(self.update_title());
+            }
+            Message::Pomodoro(pomodoro_message) => {
+                commands.push(
+                    self.pomodoro
+                        .update(pomodoro_message)
+                        .map(cosmic::app::Message::App),
+                );
+                self.save_status();
+                commands.push(self.update_title());
+            }
+            Message::Stopwatch(stopwatch_message) => {
+                commands.push(
+                    self.stopwatch
+                        .update(stopwatch_message)
+                        .map(cosmic::app::Message::App),
+                );
+            }
+            Message::Timers(timers_message) => {
+                commands.push(
+                    self.timers
+                        .update(timers_message)
+                        .map(cosmic::app::Message::App),
+                );
+            }
+            Message::Alarms(alarms_message) => {
+                commands.push(
+                    self.alarms
+                        .update(alarms_message)
+                        .map(cosmic::app::Message::App),
+                );
+            }
+            Message::WorldClock(world_clock_message) => {
+                commands.push(
+                    self.world_clock
+                        .update(world_clock_message)
+                        .map(cosmic::app::Message::App),
+                );
+            }
+            Message::Statistics(statistics_message) => {
+                commands.push(
+                    self.statistics
+                        .update(statistics_message)
+                        .map(cosmic::app::Message::App),
+                );
             }
-            Message::Pomodoro(pomodoro_message) => commands.push(
-                self.pomodoro
-                    .update(pomodoro_message)
-                    .map(cosmic::app::Message::App),
-            ),
             Message::Settings(settings_message) => commands.push(
                 self.settings
                     .update(settings_message)
@@ -253,6 +510,8 @@ impl Application for AppModel {
                         .update(pages::pomodoro::PomodoroMessage::PomodoroTick)
                         .map(cosmic::app::Message::App),
                 );
+                self.save_status();
+                commands.push(self.update_title());
             }
             Message::StartPomodoroTimer => {
                 self.pomodoro_tick_state = PomodoroTickState::Ticking {
@@ -262,6 +521,155 @@ impl Application for AppModel {
             Message::PausePomodoroTimer => {
                 self.pomodoro_tick_state = PomodoroTickState::Idle;
             }
+            Message::TimerEvent(event) => {
+                match event.kind {
+                    TimerEventKind::FocusStart => {
+                        if self.config.do_not_disturb {
+                            self.dnd.enable();
+                        }
+                        if self.config.inhibit_sleep {
+                            self.inhibitor.set(true);
+                        }
+                        self.ambience
+                            .play(self.config.ambience, self.config.ambience_volume);
+                    }
+                    TimerEventKind::BreakStart | TimerEventKind::Paused | TimerEventKind::Reset => {
+                        self.dnd.restore();
+                        self.inhibitor.set(false);
+                        self.ambience.stop();
+                    }
+                    TimerEventKind::FocusEnd | TimerEventKind::BreakEnd => {}
+                }
+
+                if event.kind == TimerEventKind::BreakStart {
+                    if self.config.break_overlay {
+                        commands.push(self.open_break_window());
+                    }
+                } else {
+                    commands.push(self.close_break_window());
+                }
+
+                if self.config.sounds_active {
+                    match event.kind {
+                        TimerEventKind::FocusEnd => self.sounds.play(
+                            Sound::FocusEnd,
+                            &self.config.focus_end_sound,
+                            self.config.sound_volume,
+                        ),
+                        TimerEventKind::BreakEnd => self.sounds.play(
+                            Sound::BreakEnd,
+                            &self.config.break_end_sound,
+                            self.config.sound_volume,
+                        ),
+                        _ => {}
+                    }
+                }
+
+                let hook = self.config.hooks.get(event.kind);
+                if !hook.is_empty() {
+                    let timeout = Duration::from_secs(self.config.hook_timeout as u64);
+                    commands.push(Task::perform(
+                        hooks::run(hook.to_owned(), event.clone(), timeout),
+                        |_| cosmic::app::Message::None,
+                    ));
+                }
+
+                let urls = self
+                    .config
+                    .webhook_urls
+                    .iter()
+                    .filter(|url| !url.is_empty())
+                    .cloned()
+                    .collect();
+                self.webhooks.post(
+                    urls,
+                    event,
+                    Duration::from_secs(self.config.webhook_timeout as u64),
+                    self.config.webhook_retries,
+                );
+            }
+            Message::Presence(event) => {
+                let (PresenceEvent::Away(reason) | PresenceEvent::Back(reason)) = event;
+                let enabled = match reason {
+                    AwayReason::Suspend | AwayReason::Lock => self.config.auto_pause_on_lock,
+                    AwayReason::Idle => self.config.auto_pause_on_idle,
+                };
+                if enabled {
+                    let message = match event {
+                        PresenceEvent::Away(_) => pages::pomodoro::PomodoroMessage::Away,
+                        PresenceEvent::Back(_) => pages::pomodoro::PomodoroMessage::Back,
+                    };
+                    commands.push(self.update(Message::Pomodoro(message)));
+                }
+            }
+            Message::PreviewSound(sound, file, volume) => {
+                self.sounds.play(sound, &file, volume);
+            }
+            Message::CountdownFinished(file) => {
+                if self.config.sounds_active {
+                    self.sounds
+                        .play(Sound::BreakEnd, &file, self.config.sound_volume);
+                }
+            }
+            Message::AlarmRang(file) => {
+                if self.config.sounds_active {
+                    self.sounds
+                        .play(Sound::FocusEnd, &file, self.config.sound_volume);
+                }
+            }
+            Message::BreakWindowClosed(id) => {
+                if self.break_window == Some(id) {
+                    self.break_window = None;
+                    commands.push(window::close(id));
+                }
+            }
+            Message::Key(modifiers, key) => {
+                for (key_bind, action) in &self.key_binds {
+                    if key_bind.matches(modifiers, &key) {
+                        return self.update(action.message());
+                    }
+                }
+            }
+            Message::Shortcut(shortcut) => match shortcut {
+                Shortcut::StartPause => {
+                    commands.push(self.handle_timer_action(TimerAction::Toggle));
+                }
+                Shortcut::Skip => {
+                    commands.push(
+                        self.update(Message::Pomodoro(pages::pomodoro::PomodoroMessage::Skip)),
+                    );
+                }
+                Shortcut::Reset => {
+                    commands.push(self.update(Message::Pomodoro(
+                        pages::pomodoro::PomodoroMessage::ResetPomodoro,
+                    )));
+                }
+                Shortcut::Settings => commands.push(self.show_page(NavPage::Settings)),
+                Shortcut::CompactMode => commands.push(self.set_compact(!self.compact)),
+                Shortcut::Page(page) => commands.push(self.show_page(page)),
+            },
+            Message::ToggleCompact => {
+                commands.push(self.set_compact(!self.compact));
+            }
+            // The compact geometry is only saved when leaving compact mode or closing
+            Message::WindowResized(id, size) => {
+                if self.core.main_window_id() == Some(id) {
+                    self.window_size = size;
+                    if self.compact {
+                        self.compact_size = Some(size);
+                    }
+                }
+            }
+            Message::WindowMoved(id, point) => {
+                if self.compact && self.core.main_window_id() == Some(id) {
+                    self.compact_position = Some(point);
+                }
+            }
+            Message::DragWindow => {
+                if let Some(id) = self.core.main_window_id() {
+                    commands.push(window::drag(id));
+                }
+            }
             Message::Open(url) => {
                 if let Err(err) = open::that_detached(url) {
                     log::error!("{err}")
@@ -271,6 +679,24 @@ impl Application for AppModel {
         Task::batch(commands)
     }
 
+    /// Called when another launch of the application forwards its arguments to this one.
+    fn dbus_activation(&mut self, msg: cosmic::dbus_activation::Message) -> Task<Self::Message> {
+        let mut commands = vec![];
+
+        if let Some(id) = self.core.main_window_id() {
+            commands.push(window::gain_focus(id));
+        }
+
+        if let cosmic::dbus_activation::Details::ActivateAction { action, .. } = msg.msg {
+            match action.parse::<TimerAction>() {
+                Ok(action) => commands.push(self.handle_timer_action(action)),
+                Err(err) => log::warn!("ignoring forwarded action: {err}"),
+            }
+        }
+
+        Task::batch(commands)
+    }
+
     /// Called when a nav item is selected.
     fn on_nav_select(&mut self, id: nav_bar::Id) -> Task<Self::Message> {
         // Activate the page in the model.
@@ -282,16 +708,161 @@ impl Application for AppModel {
 
 impl AppModel {
     /// Updates the header and window titles.
+    ///
+    /// While the timer runs, the window title starts with the remaining time so it
+    /// shows up in window switchers and docks.
     pub fn update_title(&mut self) -> Task<Message> {
         let mut window_title = fl!("app-title");
 
-        if let Some(page) = self.nav.text(self.nav.active()) {
+        if self.config.title_countdown && self.pomodoro.is_running() {
+            let status = self.pomodoro.status();
+            window_title = format!(
+                "{} {} — {}",
+                format_duration(status.remaining),
+                status.phase.label(),
+                window_title
+            );
+        } else if let Some(page) = self.nav.text(self.nav.active()) {
             window_title.push_str(" — ");
             window_title.push_str(page);
         }
 
+        if self.core.window.header_title == window_title {
+            return Task::none();
+        }
         self.set_window_title(window_title)
     }
+
+    /// Switches to `page`, leaving compact mode to show it.
+    fn show_page(&mut self, page: NavPage) -> Task<Message> {
+        let Some(id) = self
+            .nav
+            .iter()
+            .find(|&id| self.nav.data::<NavPage>(id) == Some(&page))
+        else {
+            return Task::none();
+        };
+        let compact = self.set_compact(false);
+        Task::batch(vec![compact, self.on_nav_select(id)])
+    }
+
+    /// Shrinks the main window down to the timer, or brings back the full interface.
+    fn set_compact(&mut self, compact: bool) -> Task<Message> {
+        let Some(id) = self.core.main_window_id() else {
+            return Task::none();
+        };
+        if self.compact == compact {
+            return Task::none();
+        }
+        if self.compact {
+            self.save_compact_geometry();
+            self.config = Config::load().1;
+        }
+        self.compact = compact;
+
+        let hide_chrome = compact && self.config.compact_hide_header;
+        self.core.window.show_headerbar = !hide_chrome;
+        self.core.nav_bar_set_toggled(!compact);
+
+        if compact {
+            self.normal_size = self.window_size;
+            self.compact_size = None;
+            self.compact_position = None;
+            let (width, height) = self.config.compact_size;
+            let mut commands = vec![window::resize(id, Size::new(width as f32, height as f32))];
+            if let Some((x, y)) = self.config.compact_position {
+                commands.push(window::move_to(id, Point::new(x as f32, y as f32)));
+            }
+            Task::batch(commands)
+        } else {
+            window::resize(id, self.normal_size)
+        }
+    }
+
+    /// Remembers where the compact window was left, for the next time it's used. Only
+    /// what the compact window reported is saved, the rest keeps its previous value.
+    fn save_compact_geometry(&self) {
+        let mut config = Config::load();
+        if let Some(size) = self.compact_size {
+            let _ = config.1.set_compact_size(
+                config.0.as_ref().unwrap(),
+                (size.width as u32, size.height as u32),
+            );
+        }
+        if let Some(point) = self.compact_position {
+            let _ = config
+                .1
+                .set_compact_position(&config.0.unwrap(), Some((point.x as i32, point.y as i32)));
+        }
+    }
+
+    fn compact_view(&self) -> Element<Message> {
+        let mut row = widget::row()
+            .push(
+                widget::mouse_area(self.pomodoro.compact_view().map(Message::Pomodoro))
+                    .on_press(Message::DragWindow),
+            )
+            .align_y(Alignment::Center);
+
+        if self.config.compact_hide_header {
+            row = row.push(
+                widget::button::icon(widget::icon::from_name("view-restore-symbolic"))
+                    .tooltip(fl!("compact-mode-leave"))
+                    .on_press(Message::ToggleCompact),
+            );
+        }
+
+        widget::container(row)
+            .width(Length::Fill)
+            .height(Length::Fill)
+            .align_x(Alignment::Center)
+            .align_y(Alignment::Center)
+            .into()
+    }
+
+    fn open_break_window(&mut self) -> Task<Message> {
+        if self.break_window.is_some() {
+            return Task::none();
+        }
+
+        let (id, open) = window::open(window::Settings {
+            decorations: false,
+            level: window::Level::AlwaysOnTop,
+            exit_on_close_request: false,
+            ..Default::default()
+        });
+        self.break_window = Some(id);
+
+        open.then(|id| window::change_mode(id, window::Mode::Fullscreen))
+    }
+
+    fn close_break_window(&mut self) -> Task<Message> {
+        match self.break_window.take() {
+            Some(id) => window::close(id),
+            None => Task::none(),
+        }
+    }
+
+    /// Publishes the timer's state for `chronos status`.
+    fn save_status(&self) {
+        if let Err(err) = self.pomodoro.status().save() {
+            log::debug!("failed to save status: {err}");
+        }
+    }
+
+    /// Applies a timer action requested from the command line.
+    fn handle_timer_action(&mut self, action: TimerAction) -> Task<Message> {
+        let running = self.pomodoro.is_running();
+        let message = match action {
+            TimerAction::Start if !running => pages::pomodoro::PomodoroMessage::StartPomodoro,
+            TimerAction::Pause if running => pages::pomodoro::PomodoroMessage::PausePomodoro,
+            TimerAction::Toggle if running => pages::pomodoro::PomodoroMessage::PausePomodoro,
+            TimerAction::Toggle => pages::pomodoro::PomodoroMessage::StartPomodoro,
+            TimerAction::Start | TimerAction::Pause => return Task::none(),
+        };
+
+        self.update(Message::Pomodoro(message))
+    }
 }
 
 /// The context page to display in the context drawer.
@@ -312,6 +883,14 @@ impl ContextPage {
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
 pub enum MenuAction {
     About,
+    Shortcut(Shortcut),
+}
+
+fn key_binds(config: &Config) -> HashMap<menu::KeyBind, MenuAction> {
+    shortcuts::key_binds(&config.shortcuts)
+        .into_iter()
+        .map(|(key_bind, shortcut)| (key_bind, MenuAction::Shortcut(shortcut)))
+        .collect()
 }
 
 impl menu::action::MenuAction for MenuAction {
@@ -320,6 +899,7 @@ impl menu::action::MenuAction for MenuAction {
     fn message(&self) -> Self::Message {
         match self {
             MenuAction::About => Message::ToggleContextPage,
+            MenuAction::Shortcut(shortcut) => Message::Shortcut(*shortcut),
         }
     }
 }
@@ -1,6 +1,7 @@
 // SPDX-License-Identifier: {{LICENSE}}
 
 use std::any::TypeId;
+use std::collections::BTreeMap;
 
 use cosmic::Application;
 use cosmic::{
@@ -9,6 +10,12 @@ use cosmic::{
 };
 use serde::{Deserialize, Serialize};
 
+use crate::core::alarms::Alarm;
+use crate::core::ambience::Ambience;
+use crate::core::history::GoalUnit;
+use crate::core::shortcuts::Shortcut;
+use crate::core::timer::{TimerEventKind, TimerMode};
+
 pub const CONFIG_VERSION: u64 = 1;
 
 #[derive(Clone, CosmicConfigEntry, Debug, Deserialize, Eq, PartialEq, Serialize)]
@@ -18,6 +25,51 @@ pub struct Config {
     pub long_pause_duration: u32,
     pub pomodoro_before_long_pause: u32,
     pub notifications_active: bool,
+    pub hooks: Hooks,
+    pub hook_timeout: u32,
+    pub webhook_urls: Vec<String>,
+    pub webhook_timeout: u32,
+    pub webhook_retries: u32,
+    pub do_not_disturb: bool,
+    pub inhibit_sleep: bool,
+    pub auto_pause_on_lock: bool,
+    pub auto_pause_on_idle: bool,
+    pub sounds_active: bool,
+    /// Sound files played when a phase ends, the bundled sounds when empty.
+    pub focus_end_sound: String,
+    pub break_end_sound: String,
+    pub sound_volume: u32,
+    pub ambience: Ambience,
+    pub ambience_volume: u32,
+    pub break_overlay: bool,
+    pub break_postpone_minutes: u32,
+    pub break_postpone_limit: u32,
+    pub timer_mode: TimerMode,
+    /// Flowtime breaks last this fraction of the focus time, 5 giving a fifth.
+    pub flowtime_break_ratio: u32,
+    /// Focus wanted each day, counted in `daily_goal_unit`, none when zero.
+    pub daily_goal: u32,
+    pub daily_goal_unit: GoalUnit,
+    pub daily_summary: bool,
+    /// Hour and minute at which the day's summary is shown.
+    pub daily_summary_time: (u32, u32),
+    /// Ask for a note and a rating once a focus block ends.
+    pub reflection_prompt: bool,
+    /// List breaks in the history along with focus blocks.
+    pub history_show_breaks: bool,
+    pub compact_size: (u32, u32),
+    pub compact_position: Option<(i32, i32)>,
+    pub compact_hide_header: bool,
+    /// Show the remaining time in the window title while the timer runs.
+    pub title_countdown: bool,
+    pub alarms: Vec<Alarm>,
+    pub alarm_snooze_minutes: u32,
+    /// IANA zones pinned on the world clock.
+    pub world_clocks: Vec<String>,
+    /// First and last hour of the working day, in every zone.
+    pub working_hours: (u32, u32),
+    /// Bindings changed by the user, the others keep their default.
+    pub shortcuts: BTreeMap<Shortcut, String>,
 }
 
 impl Default for Config {
@@ -28,10 +80,81 @@ impl Default for Config {
             long_pause_duration: 15,
             pomodoro_before_long_pause: 4,
             notifications_active: false,
+            hooks: Hooks::default(),
+            hook_timeout: 10,
+            webhook_urls: Vec::new(),
+            webhook_timeout: 5,
+            webhook_retries: 2,
+            do_not_disturb: false,
+            inhibit_sleep: false,
+            auto_pause_on_lock: false,
+            auto_pause_on_idle: false,
+            sounds_active: false,
+            focus_end_sound: String::new(),
+            break_end_sound: String::new(),
+            sound_volume: 80,
+            ambience: Ambience::Off,
+            ambience_volume: 40,
+            break_overlay: false,
+            break_postpone_minutes: 5,
+            break_postpone_limit: 2,
+            timer_mode: TimerMode::Pomodoro,
+            flowtime_break_ratio: 5,
+            daily_goal: 0,
+            daily_goal_unit: GoalUnit::Pomodoros,
+            daily_summary: false,
+            daily_summary_time: (18, 0),
+            reflection_prompt: false,
+            history_show_breaks: true,
+            compact_size: (280, 220),
+            compact_position: None,
+            compact_hide_header: true,
+            title_countdown: true,
+            alarms: Vec::new(),
+            alarm_snooze_minutes: 10,
+            world_clocks: Vec::new(),
+            working_hours: (9, 17),
+            shortcuts: BTreeMap::new(),
         }
     }
 }
 
+/// Executables run on timer events, empty when unset.
+#[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+pub struct Hooks {
+    pub focus_start: String,
+    pub focus_end: String,
+    pub break_start: String,
+    pub break_end: String,
+    pub paused: String,
+    pub reset: String,
+}
+
+impl Hooks {
+    pub fn get(&self, kind: TimerEventKind) -> &str {
+        match kind {
+            TimerEventKind::FocusStart => &self.focus_start,
+            TimerEventKind::FocusEnd => &self.focus_end,
+            TimerEventKind::BreakStart => &self.break_start,
+            TimerEventKind::BreakEnd => &self.break_end,
+            TimerEventKind::Paused => &self.paused,
+            TimerEventKind::Reset => &self.reset,
+        }
+    }
+
+    pub fn set(&mut self, kind: TimerEventKind, program: String) {
+        let hook = match kind {
+            TimerEventKind::FocusStart => &mut self.focus_start,
+            TimerEventKind::FocusEnd => &mut self.focus_end,
+            TimerEventKind::BreakStart => &mut self.break_start,
+            TimerEventKind::BreakEnd => &mut self.break_end,
+            TimerEventKind::Paused => &mut self.paused,
+            TimerEventKind::Reset => &mut self.reset,
+        };
+        *hook = program;
+    }
+}
+
 impl Config {
     pub fn load() -> (Option<cosmic_config::Config>, Self) {
         match cosmic_config::Config::new(crate::app::AppModel::APP_ID, CONFIG_VERSION) {
@@ -0,0 +1,203 @@
+// SPDX-License-Identifier: {{LICENSE}}
+
+//! Alarms ringing at a wall clock time, once or on chosen weekdays.
+//!
+//! Snoozed alarms are saved in the application's state config, so a snooze still
+//! rings after a restart.
+
+use std::any::TypeId;
+use std::hash::Hash;
+use std::time::Duration;
+
+use chrono::{DateTime, Datelike, Days, Local, NaiveTime, Weekday};
+use cosmic::cosmic_config::{self, ConfigGet, ConfigSet};
+use cosmic::iced::futures::SinkExt;
+use cosmic::iced::{stream, Subscription};
+use cosmic::Application;
+use serde::{Deserialize, Serialize};
+
+use crate::config::CONFIG_VERSION;
+use crate::fl;
+
+const SNOOZED_KEY: &str = "snoozed-alarms";
+
+#[derive(Clone, Debug, Default, Eq, PartialEq, Deserialize, Serialize)]
+pub struct Alarm {
+    pub label: String,
+    pub hour: u32,
+    pub minute: u32,
+    /// Weekdays it repeats on, from Monday. It rings only once when none is set.
+    pub days: [bool; 7],
+    pub enabled: bool,
+    /// Sound file played when it rings, the bundled sound when empty.
+    pub sound: String,
+}
+
+impl Alarm {
+    pub fn repeats(&self) -> bool {
+        self.days.iter().any(|&day| day)
+    }
+
+    fn rings_on(&self, weekday: Weekday) -> bool {
+        !self.repeats() || self.days[weekday.num_days_from_monday() as usize]
+    }
+
+    /// The first time it rings strictly after `after`.
+    pub fn next_after(&self, after: DateTime<Local>) -> Option<DateTime<Local>> {
+        if !self.enabled {
+            return None;
+        }
+        let time = NaiveTime::from_hms_opt(self.hour, self.minute, 0)?;
+
+        (0..=7).find_map(|offset| {
+            let date = after.date_naive().checked_add_days(Days::new(offset))?;
+            if !self.rings_on(date.weekday()) {
+                return None;
+            }
+            // Times skipped by a daylight saving change don't exist that day
+            let at = date.and_time(time).and_local_timezone(Local).earliest()?;
+            (at > after).then_some(at)
+        })
+    }
+}
+
+/// An alarm that rang and was put off.
+#[derive(Clone, Debug, Eq, PartialEq, Deserialize, Serialize)]
+pub struct Snoozed {
+    pub alarm: Alarm,
+    /// Unix time at which it rings again.
+    pub at: i64,
+}
+
+/// Emits once `at`, a Unix time, has been reached.
+///
+/// The wall clock is checked again every half minute rather than sleeping for the
+/// whole wait, so suspending the computer or changing its clock doesn't delay it.
+/// `id` tells apart the subscriptions waiting for the same time.
+pub fn subscription(id: impl Hash + 'static, at: i64) -> Subscription<()> {
+    struct AlarmSubscription;
+
+    Subscription::run_with_id(
+        (TypeId::of::<AlarmSubscription>(), id, at),
+        stream::channel(1, move |mut output| async move {
+            loop {
+                let left = at - Local::now().timestamp();
+                if left <= 0 {
+                    break;
+                }
+                tokio::time::sleep(Duration::from_secs(left.min(30) as u64)).await;
+            }
+            let _ = output.send(()).await;
+            std::future::pending::<()>().await;
+        }),
+    )
+}
+
+fn state() -> Option<cosmic_config::Config> {
+    cosmic_config::Config::new_state(crate::app::AppModel::APP_ID, CONFIG_VERSION)
+        .map_err(|err| log::warn!("failed to open state config: {err}"))
+        .ok()
+}
+
+/// Snoozed alarms saved by [`save_snoozed`].
+pub fn load_snoozed() -> Vec<Snoozed> {
+    state()
+        .and_then(|state| state.get::<Vec<Snoozed>>(SNOOZED_KEY).ok())
+        .unwrap_or_default()
+}
+
+pub fn save_snoozed(snoozed: &[Snoozed]) {
+    if let Some(state) = state() {
+        if let Err(err) = state.set(SNOOZED_KEY, snoozed) {
+            log::error!("failed to save snoozed alarms: {err}");
+        }
+    }
+}
+
+/// Short names of the weekdays, from Monday.
+pub fn weekday_names() -> [String; 7] {
+    [
+        fl!("weekday-mon"),
+        fl!("weekday-tue"),
+        fl!("weekday-wed"),
+        fl!("weekday-thu"),
+        fl!("weekday-fri"),
+        fl!("weekday-sat"),
+        fl!("weekday-sun"),
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::TimeZone;
+
+    use super::*;
+
+    /// Monday 15 January 2024, far from any daylight saving change.
+    fn monday(hour: u32, minute: u32) -> DateTime<Local> {
+        Local
+            .with_ymd_and_hms(2024, 1, 15, hour, minute, 0)
+            .unwrap()
+    }
+
+    fn alarm(hour: u32, minute: u32, days: [bool; 7]) -> Alarm {
+        Alarm {
+            hour,
+            minute,
+            days,
+            enabled: true,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn rings_once_at_the_next_time() {
+        let alarm = alarm(7, 30, [false; 7]);
+        assert_eq!(alarm.next_after(monday(6, 0)), Some(monday(7, 30)));
+        assert_eq!(
+            alarm.next_after(monday(8, 0)),
+            Some(monday(7, 30) + Days::new(1))
+        );
+    }
+
+    #[test]
+    fn rings_strictly_after() {
+        let alarm = alarm(7, 30, [false; 7]);
+        assert_eq!(
+            alarm.next_after(monday(7, 30)),
+            Some(monday(7, 30) + Days::new(1))
+        );
+    }
+
+    #[test]
+    fn repeats_on_chosen_weekdays() {
+        // Mondays and Wednesdays
+        let alarm = alarm(9, 0, [true, false, true, false, false, false, false]);
+        assert_eq!(alarm.next_after(monday(8, 0)), Some(monday(9, 0)));
+        assert_eq!(
+            alarm.next_after(monday(10, 0)),
+            Some(monday(9, 0) + Days::new(2))
+        );
+
+        // Only Sundays, from the Monday after
+        let mut sundays = [false; 7];
+        sundays[6] = true;
+        let alarm = Alarm {
+            days: sundays,
+            ..alarm
+        };
+        assert_eq!(
+            alarm.next_after(monday(10, 0)),
+            Some(monday(9, 0) + Days::new(6))
+        );
+    }
+
+    #[test]
+    fn disabled_alarms_never_ring() {
+        let alarm = Alarm {
+            enabled: false,
+            ..alarm(7, 30, [true; 7])
+        };
+        assert_eq!(alarm.next_after(monday(6, 0)), None);
+    }
+}
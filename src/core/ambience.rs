@@ -0,0 +1,236 @@
+// SPDX-License-Identifier: {{LICENSE}}
+
+//! Background sound played while a focus block runs.
+//!
+//! The sound is generated on the fly and streamed as raw samples to the PipeWire or
+//! PulseAudio command line player, which lets it fade in and out smoothly.
+
+use std::io::{self, Write};
+use std::process::{Child, Command, Stdio};
+use std::sync::atomic::{AtomicBool, AtomicU32, Ordering};
+use std::sync::Arc;
+use std::thread;
+use std::time::{Duration, Instant};
+
+use serde::{Deserialize, Serialize};
+
+const RATE: u32 = 44_100;
+/// Samples generated at once, a tenth of a second.
+const CHUNK: usize = RATE as usize / 10;
+const FADE_IN_CHUNKS: u32 = 10;
+const FADE_OUT_CHUNKS: u32 = 15;
+/// Longest wait for the player to play what it buffered once the input ends.
+const DRAIN_TIMEOUT: Duration = Duration::from_secs(3);
+
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Deserialize, Serialize)]
+pub enum Ambience {
+    #[default]
+    Off,
+    Tick,
+    WhiteNoise,
+    BrownNoise,
+}
+
+impl Ambience {
+    pub fn all() -> &'static [Self] {
+        &[Self::Off, Self::Tick, Self::WhiteNoise, Self::BrownNoise]
+    }
+}
+
+struct Playback {
+    ambience: Ambience,
+    stop: Arc<AtomicBool>,
+}
+
+#[derive(Default)]
+pub struct AmbiencePlayer {
+    playback: Option<Playback>,
+    volume: Arc<AtomicU32>,
+}
+
+impl AmbiencePlayer {
+    /// Fades `ambience` in, replacing whatever is playing.
+    pub fn play(&mut self, ambience: Ambience, volume: u32) {
+        self.set_volume(volume);
+        if self.playing() == Some(ambience) {
+            return;
+        }
+        self.stop();
+        if ambience == Ambience::Off {
+            return;
+        }
+
+        let child = match spawn_player() {
+            Ok(child) => child,
+            Err(err) => {
+                log::error!("failed to start ambience player: {err}");
+                return;
+            }
+        };
+
+        let stop = Arc::new(AtomicBool::new(false));
+        let volume = self.volume.clone();
+        let thread_stop = stop.clone();
+        let spawned = thread::Builder::new()
+            .name("chronos-ambience".into())
+            .spawn(move || stream(child, ambience, volume, thread_stop));
+        if let Err(err) = spawned {
+            log::error!("failed to start ambience thread: {err}");
+            return;
+        }
+
+        self.playback = Some(Playback { ambience, stop });
+    }
+
+    /// Fades out and stops the current sound.
+    pub fn stop(&mut self) {
+        if let Some(playback) = self.playback.take() {
+            playback.stop.store(true, Ordering::Relaxed);
+        }
+    }
+
+    pub fn playing(&self) -> Option<Ambience> {
+        self.playback.as_ref().map(|playback| playback.ambience)
+    }
+
+    pub fn set_volume(&self, volume: u32) {
+        self.volume.store(volume.min(100), Ordering::Relaxed);
+    }
+}
+
+impl Drop for AmbiencePlayer {
+    fn drop(&mut self) {
+        self.stop();
+    }
+}
+
+fn spawn_player() -> io::Result<Child> {
+    let players: [(&str, &[&str]); 2] = [
+        (
+            "pw-cat",
+            &[
+                "--playback",
+                "--format=s16",
+                "--rate=44100",
+                "--channels=1",
+                "-",
+            ],
+        ),
+        (
+            "pacat",
+            &[
+                "--playback",
+                "--format=s16le",
+                "--rate=44100",
+                "--channels=1",
+            ],
+        ),
+    ];
+
+    let mut last_err = io::Error::from(io::ErrorKind::NotFound);
+    for (program, args) in players {
+        match Command::new(program)
+            .args(args)
+            .stdin(Stdio::piped())
+            .stdout(Stdio::null())
+            .stderr(Stdio::null())
+            .spawn()
+        {
+            Ok(child) => return Ok(child),
+            Err(err) => last_err = err,
+        }
+    }
+    Err(last_err)
+}
+
+/// Writes samples until asked to stop. Writing blocks while the player's buffer is
+/// full, which paces generation to playback.
+fn stream(mut child: Child, ambience: Ambience, volume: Arc<AtomicU32>, stop: Arc<AtomicBool>) {
+    let Some(mut stdin) = child.stdin.take() else {
+        return;
+    };
+    let mut generator = Generator::new(ambience);
+    let mut chunk = vec![0u8; CHUNK * 2];
+    let mut fade_in = 0;
+    let mut fade_out = FADE_OUT_CHUNKS;
+
+    loop {
+        let fade = if stop.load(Ordering::Relaxed) {
+            if fade_out == 0 {
+                break;
+            }
+            fade_out -= 1;
+            fade_out as f32 / FADE_OUT_CHUNKS as f32
+        } else {
+            fade_in = (fade_in + 1).min(FADE_IN_CHUNKS);
+            fade_in as f32 / FADE_IN_CHUNKS as f32
+        };
+        let gain = fade * volume.load(Ordering::Relaxed) as f32 / 100.;
+
+        for bytes in chunk.chunks_exact_mut(2) {
+            let sample = (generator.sample() * gain).clamp(-1., 1.);
+            bytes.copy_from_slice(&((sample * i16::MAX as f32) as i16).to_le_bytes());
+        }
+        if let Err(err) = stdin.write_all(&chunk) {
+            log::warn!("ambience player stopped: {err}");
+            break;
+        }
+    }
+
+    // The player exits once it played what it buffered, the end of the fade-out
+    drop(stdin);
+    let deadline = Instant::now() + DRAIN_TIMEOUT;
+    while matches!(child.try_wait(), Ok(None)) && Instant::now() < deadline {
+        thread::sleep(Duration::from_millis(50));
+    }
+    let _ = child.kill();
+    let _ = child.wait();
+}
+
+struct Generator {
+    ambience: Ambience,
+    position: u32,
+    seed: u32,
+    brown: f32,
+}
+
+impl Generator {
+    fn new(ambience: Ambience) -> Self {
+        Self {
+            ambience,
+            position: 0,
+            seed: 0x9e37_79b9,
+            brown: 0.,
+        }
+    }
+
+    fn white(&mut self) -> f32 {
+        // xorshift32, plenty for noise.
+        self.seed ^= self.seed << 13;
+        self.seed ^= self.seed >> 17;
+        self.seed ^= self.seed << 5;
+        self.seed as f32 / u32::MAX as f32 * 2. - 1.
+    }
+
+    fn sample(&mut self) -> f32 {
+        self.position = (self.position + 1) % RATE;
+        match self.ambience {
+            Ambience::Off => 0.,
+            // A short decaying click once per second.
+            Ambience::Tick => {
+                let t = self.position as f32 / RATE as f32;
+                if t < 0.02 {
+                    (t * 2. * std::f32::consts::PI * 1500.).sin() * (-t * 250.).exp() * 0.6
+                } else {
+                    0.
+                }
+            }
+            Ambience::WhiteNoise => self.white() * 0.25,
+            Ambience::BrownNoise => {
+                let white = self.white();
+                self.brown = (self.brown + 0.02 * white) / 1.02;
+                self.brown * 3.5
+            }
+        }
+    }
+}
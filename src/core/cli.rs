@@ -0,0 +1,168 @@
+// SPDX-License-Identifier: {{LICENSE}}
+
+//! Command line handling shared between the first and any later launch.
+
+use std::fmt;
+use std::str::FromStr;
+
+use cosmic::app::CosmicFlags;
+
+use crate::core::status::StatusOptions;
+
+const USAGE: &str = "Usage: chronos [start | pause | toggle]
+       chronos status [--format <template>] [--follow]
+
+Commands:
+  start    Start or resume the pomodoro timer
+  pause    Pause the pomodoro timer
+  toggle   Start the timer if it is paused, pause it otherwise
+  status   Print the state of the running timer
+
+When Chronos is already running, the command is forwarded to the open window.
+
+Status options:
+  -f, --format <template>  Template with {phase}, {state}, {remaining},
+                           {remaining_seconds}, {progress}, {cycle}, {cycles}
+                           and {task} placeholders [default: \"{phase} {remaining}\"]
+      --follow             Print a JSON object every second";
+
+/// Timer actions which can be requested from the command line.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum TimerAction {
+    Start,
+    Pause,
+    Toggle,
+}
+
+impl FromStr for TimerAction {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "start" => Ok(Self::Start),
+            "pause" => Ok(Self::Pause),
+            "toggle" => Ok(Self::Toggle),
+            other => Err(format!("unknown command '{other}'")),
+        }
+    }
+}
+
+impl fmt::Display for TimerAction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Start => "start",
+            Self::Pause => "pause",
+            Self::Toggle => "toggle",
+        })
+    }
+}
+
+/// Flags handed to the application on startup.
+///
+/// When another instance already owns the application's D-Bus name, the action is
+/// forwarded to it instead and this process exits.
+#[derive(Clone, Debug, Default)]
+pub struct Flags {
+    pub action: Option<TimerAction>,
+}
+
+impl CosmicFlags for Flags {
+    type SubCommand = TimerAction;
+    type Args = Vec<String>;
+
+    fn action(&self) -> Option<&Self::SubCommand> {
+        self.action.as_ref()
+    }
+}
+
+/// What the process was asked to do.
+pub enum Command {
+    Run(Flags),
+    Status(StatusOptions),
+    Help,
+}
+
+/// Parses the arguments following the executable name.
+pub fn parse(mut args: impl Iterator<Item = String>) -> Result<Command, String> {
+    let Some(arg) = args.next() else {
+        return Ok(Command::Run(Flags::default()));
+    };
+
+    if arg == "-h" || arg == "--help" {
+        return Ok(Command::Help);
+    }
+
+    // Desktop launchers may pass file arguments (`%F`), which Chronos ignores.
+    if !arg.starts_with('-') && arg.contains('/') {
+        return Ok(Command::Run(Flags::default()));
+    }
+
+    if arg == "status" {
+        return parse_status(args).map(Command::Status);
+    }
+
+    let action = arg.parse::<TimerAction>()?;
+    if let Some(extra) = args.next() {
+        return Err(format!("unexpected argument '{extra}'"));
+    }
+
+    Ok(Command::Run(Flags {
+        action: Some(action),
+    }))
+}
+
+fn parse_status(mut args: impl Iterator<Item = String>) -> Result<StatusOptions, String> {
+    let mut options = StatusOptions::default();
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "-f" | "--format" => {
+                let template = args.next().ok_or("missing template after --format")?;
+                options.format = Some(template);
+            }
+            "--follow" => options.follow = true,
+            other => match other.strip_prefix("--format=") {
+                Some(template) => options.format = Some(template.to_owned()),
+                None => return Err(format!("unexpected argument '{other}'")),
+            },
+        }
+    }
+
+    Ok(options)
+}
+
+pub fn usage() -> &'static str {
+    USAGE
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse_args(args: &[&str]) -> Result<Command, String> {
+        parse(args.iter().map(|arg| arg.to_string()))
+    }
+
+    #[test]
+    fn parses_status_options() {
+        let Ok(Command::Status(options)) =
+            parse_args(&["status", "--format", "{phase}", "--follow"])
+        else {
+            panic!("not a status command");
+        };
+        assert_eq!(options.format.as_deref(), Some("{phase}"));
+        assert!(options.follow);
+
+        let Ok(Command::Status(options)) = parse_args(&["status", "--format={remaining}"]) else {
+            panic!("not a status command");
+        };
+        assert_eq!(options.format.as_deref(), Some("{remaining}"));
+        assert!(!options.follow);
+    }
+
+    #[test]
+    fn rejects_bad_status_options() {
+        assert!(parse_args(&["status", "--format"]).is_err());
+        assert!(parse_args(&["status", "--nope"]).is_err());
+    }
+}
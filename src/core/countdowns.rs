@@ -0,0 +1,158 @@
+// SPDX-License-Identifier: {{LICENSE}}
+
+//! Named countdowns running alongside the pomodoro.
+//!
+//! Running countdowns store the wall clock time they end at, so they keep counting
+//! while Chronos is closed and are saved in the application's state config.
+
+use cosmic::cosmic_config::{self, ConfigGet, ConfigSet};
+use cosmic::Application;
+use serde::{Deserialize, Serialize};
+
+use crate::config::CONFIG_VERSION;
+
+const COUNTDOWNS_KEY: &str = "countdowns";
+
+#[derive(Clone, Debug, Default, Eq, PartialEq, Deserialize, Serialize)]
+pub struct Countdown {
+    pub name: String,
+    /// Length in seconds.
+    pub duration: u32,
+    /// Sound file played when it ends, the bundled sound when empty.
+    pub sound: String,
+    /// Unix time the countdown ends at, while it runs.
+    pub ends_at: Option<u64>,
+    /// Seconds left while it doesn't run.
+    pub remaining: u32,
+}
+
+impl Countdown {
+    pub fn new(name: String, duration: u32, sound: String) -> Self {
+        Self {
+            name,
+            duration,
+            sound,
+            ends_at: None,
+            remaining: duration,
+        }
+    }
+
+    pub fn is_running(&self) -> bool {
+        self.ends_at.is_some()
+    }
+
+    /// Seconds left at `now`.
+    pub fn remaining_at(&self, now: u64) -> u32 {
+        match self.ends_at {
+            Some(ends_at) => ends_at.saturating_sub(now) as u32,
+            None => self.remaining,
+        }
+    }
+
+    /// Whether it ran out by `now`, possibly while Chronos was closed.
+    pub fn has_finished(&self, now: u64) -> bool {
+        self.is_running() && self.remaining_at(now) == 0
+    }
+
+    pub fn start(&mut self, now: u64) {
+        if self.ends_at.is_none() && self.remaining > 0 {
+            self.ends_at = Some(now + self.remaining as u64);
+        }
+    }
+
+    pub fn pause(&mut self, now: u64) {
+        self.remaining = self.remaining_at(now);
+        self.ends_at = None;
+    }
+
+    pub fn reset(&mut self) {
+        self.ends_at = None;
+        self.remaining = self.duration;
+    }
+}
+
+fn state() -> Option<cosmic_config::Config> {
+    cosmic_config::Config::new_state(crate::app::AppModel::APP_ID, CONFIG_VERSION)
+        .map_err(|err| log::warn!("failed to open state config: {err}"))
+        .ok()
+}
+
+/// Countdowns saved by [`save`], empty on the first run.
+pub fn load() -> Vec<Countdown> {
+    state()
+        .and_then(|state| state.get::<Vec<Countdown>>(COUNTDOWNS_KEY).ok())
+        .unwrap_or_default()
+}
+
+pub fn save(countdowns: &[Countdown]) {
+    if let Some(state) = state() {
+        if let Err(err) = state.set(COUNTDOWNS_KEY, countdowns) {
+            log::error!("failed to save countdowns: {err}");
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn countdown() -> Countdown {
+        Countdown::new("Tea".into(), 300, String::new())
+    }
+
+    #[test]
+    fn ends_its_duration_after_being_started() {
+        let mut countdown = countdown();
+        assert!(!countdown.is_running());
+        assert_eq!(countdown.remaining_at(1_000), 300);
+
+        countdown.start(1_000);
+        assert_eq!(countdown.ends_at, Some(1_300));
+        assert_eq!(countdown.remaining_at(1_100), 200);
+
+        // Starting it again doesn't move its end
+        countdown.start(1_200);
+        assert_eq!(countdown.ends_at, Some(1_300));
+    }
+
+    #[test]
+    fn resumes_where_it_was_paused() {
+        let mut countdown = countdown();
+        countdown.start(1_000);
+        countdown.pause(1_120);
+        assert!(!countdown.is_running());
+        assert_eq!(countdown.remaining_at(5_000), 180);
+
+        countdown.start(5_000);
+        assert_eq!(countdown.ends_at, Some(5_180));
+
+        countdown.reset();
+        assert_eq!(countdown.remaining_at(5_100), 300);
+        assert!(!countdown.is_running());
+    }
+
+    #[test]
+    fn finishes_while_closed() {
+        let mut countdown = countdown();
+        countdown.start(1_000);
+        assert!(!countdown.has_finished(1_299));
+
+        // Saved and loaded again long after it ended
+        let countdown: Countdown =
+            serde_json::from_value(serde_json::to_value(&countdown).unwrap()).unwrap();
+        assert!(countdown.has_finished(90_000));
+        assert_eq!(countdown.remaining_at(90_000), 0);
+    }
+
+    #[test]
+    fn a_finished_countdown_does_not_start() {
+        let mut countdown = countdown();
+        countdown.start(1_000);
+        countdown.pause(2_000);
+        assert_eq!(countdown.remaining, 0);
+
+        countdown.start(2_000);
+        assert!(!countdown.is_running());
+        assert!(!countdown.has_finished(2_000));
+    }
+}
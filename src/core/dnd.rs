@@ -0,0 +1,97 @@
+// SPDX-License-Identifier: {{LICENSE}}
+
+//! Toggles COSMIC's do-not-disturb mode during focus blocks.
+//!
+//! The state found before enabling it is kept in the application's state config, so
+//! it can still be restored on the next launch if Chronos didn't exit cleanly.
+
+use cosmic::cosmic_config::{self, ConfigGet, ConfigSet};
+use cosmic::Application;
+
+use crate::config::CONFIG_VERSION;
+
+const NOTIFICATIONS_ID: &str = "com.system76.CosmicNotifications";
+const NOTIFICATIONS_VERSION: u64 = 1;
+const DND_KEY: &str = "do_not_disturb";
+const RESTORE_KEY: &str = "do_not_disturb_restore";
+
+pub struct DoNotDisturb {
+    notifications: Option<cosmic_config::Config>,
+    state: Option<cosmic_config::Config>,
+    /// The user's own setting while Chronos has do-not-disturb enabled.
+    previous: Option<bool>,
+}
+
+impl DoNotDisturb {
+    /// Connects to the notification daemon's config and restores any state left
+    /// behind by a previous run.
+    pub fn recover() -> Self {
+        let notifications = cosmic_config::Config::new(NOTIFICATIONS_ID, NOTIFICATIONS_VERSION)
+            .map_err(|err| log::warn!("failed to open notifications config: {err}"))
+            .ok();
+        let state = cosmic_config::Config::new_state(crate::app::AppModel::APP_ID, CONFIG_VERSION)
+            .map_err(|err| log::warn!("failed to open state config: {err}"))
+            .ok();
+
+        let previous = state
+            .as_ref()
+            .and_then(|state| state.get::<Option<bool>>(RESTORE_KEY).ok())
+            .flatten();
+
+        let mut dnd = Self {
+            notifications,
+            state,
+            previous,
+        };
+        if dnd.previous.is_some() {
+            log::info!("restoring do-not-disturb left enabled by a previous run");
+            dnd.restore();
+        }
+        dnd
+    }
+
+    /// Enables do-not-disturb, remembering the user's setting.
+    pub fn enable(&mut self) {
+        let Some(notifications) = &self.notifications else {
+            return;
+        };
+        if self.previous.is_some() {
+            return;
+        }
+
+        let previous = notifications.get::<bool>(DND_KEY).unwrap_or_default();
+        self.save_previous(Some(previous));
+        if let Err(err) = notifications.set(DND_KEY, true) {
+            log::error!("failed to enable do-not-disturb: {err}");
+        }
+        self.previous = Some(previous);
+    }
+
+    /// Puts back the setting found before [`Self::enable`].
+    pub fn restore(&mut self) {
+        let (Some(notifications), Some(previous)) = (&self.notifications, self.previous) else {
+            return;
+        };
+
+        if let Err(err) = notifications.set(DND_KEY, previous) {
+            log::error!("failed to restore do-not-disturb: {err}");
+            return;
+        }
+        self.previous = None;
+        self.save_previous(None);
+    }
+
+    fn save_previous(&self, previous: Option<bool>) {
+        if let Some(state) = &self.state {
+            if let Err(err) = state.set(RESTORE_KEY, previous) {
+                log::error!("failed to save do-not-disturb state: {err}");
+            }
+        }
+    }
+}
+
+impl Drop for DoNotDisturb {
+    fn drop(&mut self) {
+        self.restore();
+    }
+}
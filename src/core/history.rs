@@ -0,0 +1,465 @@
+// SPDX-License-Identifier: {{LICENSE}}
+
+//! Completed focus blocks and breaks, kept across restarts.
+//!
+//! The history is saved in the application's state config along with the countdowns,
+//! and feeds the daily goal and the end-of-day summary.
+
+use std::collections::BTreeMap;
+
+use chrono::{DateTime, Datelike, Days, Local, NaiveDate, Timelike};
+use cosmic::cosmic_config::{self, ConfigGet, ConfigSet};
+use cosmic::Application;
+use serde::{Deserialize, Serialize};
+
+use crate::config::CONFIG_VERSION;
+use crate::core::alarms::{self, Alarm};
+use crate::fl;
+
+const HISTORY_KEY: &str = "history";
+/// Days entries are kept for after they ended.
+const KEEP_DAYS: u64 = 366;
+
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Deserialize, Serialize)]
+pub enum EntryKind {
+    #[default]
+    Focus,
+    Break,
+}
+
+#[derive(Clone, Debug, Default, Eq, PartialEq, Deserialize, Serialize)]
+pub struct Entry {
+    pub kind: EntryKind,
+    /// Unix time at which it was first started.
+    pub started_at: u64,
+    /// Unix time at which it ended.
+    pub ended_at: u64,
+    /// Time actually spent in it, in seconds, without pauses.
+    pub duration: u32,
+    /// Task worked on, empty when none was set.
+    pub task: String,
+    #[serde(default)]
+    pub interruptions: Vec<Interruption>,
+    /// What was done, written once the block ended.
+    #[serde(default)]
+    pub note: String,
+    /// How focused it felt, from 1 to 5.
+    #[serde(default)]
+    pub rating: Option<u8>,
+}
+
+impl EntryKind {
+    pub fn all() -> &'static [Self] {
+        &[Self::Focus, Self::Break]
+    }
+
+    pub fn label(&self) -> String {
+        match self {
+            Self::Focus => fl!("pomodoro"),
+            Self::Break => fl!("pause"),
+        }
+    }
+}
+
+impl Entry {
+    /// Local day it ended on.
+    pub fn date(&self) -> NaiveDate {
+        local_date(self.ended_at)
+    }
+
+    /// Whether its task or note contains `search`, ignoring case.
+    pub fn matches(&self, search: &str) -> bool {
+        let search = search.to_lowercase();
+        self.task.to_lowercase().contains(&search) || self.note.to_lowercase().contains(&search)
+    }
+}
+
+/// Where an interruption came from.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Deserialize, Serialize)]
+pub enum InterruptionKind {
+    /// Our own urge to do something else.
+    #[default]
+    Internal,
+    /// Someone or something else.
+    External,
+}
+
+impl InterruptionKind {
+    pub fn all() -> &'static [Self] {
+        &[Self::Internal, Self::External]
+    }
+
+    pub fn label(&self) -> String {
+        match self {
+            Self::Internal => fl!("interruption-internal"),
+            Self::External => fl!("interruption-external"),
+        }
+    }
+}
+
+/// Something that broke the focus of a block, logged without stopping the timer.
+#[derive(Clone, Debug, Default, Eq, PartialEq, Deserialize, Serialize)]
+pub struct Interruption {
+    pub kind: InterruptionKind,
+    /// Unix time at which it was logged.
+    pub at: u64,
+    pub note: String,
+}
+
+/// What a daily goal counts.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Deserialize, Serialize)]
+pub enum GoalUnit {
+    #[default]
+    Pomodoros,
+    Minutes,
+}
+
+impl GoalUnit {
+    pub fn all() -> &'static [Self] {
+        &[Self::Pomodoros, Self::Minutes]
+    }
+
+    pub fn label(&self) -> String {
+        match self {
+            Self::Pomodoros => fl!("goal-pomodoros"),
+            Self::Minutes => fl!("minutes"),
+        }
+    }
+}
+
+/// Totals of one day, or of any run of entries.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct DaySummary {
+    pub focus_blocks: u32,
+    pub focus_seconds: u32,
+    pub break_seconds: u32,
+    pub internal_interruptions: u32,
+    pub external_interruptions: u32,
+    /// Sum of the ratings given to focus blocks.
+    pub ratings: u32,
+    pub rated_blocks: u32,
+    /// Tasks worked on, in the order they were first focused on.
+    pub tasks: Vec<String>,
+}
+
+impl DaySummary {
+    pub fn new(entries: &[Entry], date: NaiveDate) -> Self {
+        let mut summary = Self::default();
+        for entry in entries.iter().filter(|entry| entry.date() == date) {
+            summary.add(entry);
+        }
+        summary
+    }
+
+    /// Counts `entry` in the totals.
+    pub fn add(&mut self, entry: &Entry) {
+        match entry.kind {
+            EntryKind::Focus => {
+                self.focus_blocks += 1;
+                self.focus_seconds += entry.duration;
+                for interruption in &entry.interruptions {
+                    match interruption.kind {
+                        InterruptionKind::Internal => self.internal_interruptions += 1,
+                        InterruptionKind::External => self.external_interruptions += 1,
+                    }
+                }
+                if let Some(rating) = entry.rating {
+                    self.ratings += rating as u32;
+                    self.rated_blocks += 1;
+                }
+                let task = entry.task.trim();
+                if !task.is_empty() && !self.tasks.iter().any(|known| known == task) {
+                    self.tasks.push(task.to_string());
+                }
+            }
+            EntryKind::Break => self.break_seconds += entry.duration,
+        }
+    }
+
+    /// Average rating of the rated focus blocks.
+    pub fn average_rating(&self) -> Option<f32> {
+        (self.rated_blocks > 0).then(|| self.ratings as f32 / self.rated_blocks as f32)
+    }
+
+    pub fn interruptions(&self) -> u32 {
+        self.internal_interruptions + self.external_interruptions
+    }
+
+    /// Progress toward a daily goal counted in `unit`.
+    pub fn progress(&self, unit: GoalUnit) -> u32 {
+        match unit {
+            GoalUnit::Pomodoros => self.focus_blocks,
+            GoalUnit::Minutes => self.focus_seconds / 60,
+        }
+    }
+}
+
+/// Totals of the entries that ended on `from` or later, overall and for each day.
+///
+/// `entries` must be in chronological order, as the history is kept.
+pub fn summarize_since(
+    entries: &[Entry],
+    from: NaiveDate,
+) -> (DaySummary, BTreeMap<NaiveDate, DaySummary>) {
+    let start = entries.partition_point(|entry| entry.date() < from);
+    let mut total = DaySummary::default();
+    let mut days = BTreeMap::<NaiveDate, DaySummary>::new();
+    for entry in &entries[start..] {
+        total.add(entry);
+        days.entry(entry.date()).or_default().add(entry);
+    }
+    (total, days)
+}
+
+pub fn local_date(time: u64) -> NaiveDate {
+    DateTime::from_timestamp(time as i64, 0)
+        .map(|time| time.with_timezone(&Local).date_naive())
+        .unwrap_or_default()
+}
+
+/// Local hour and minute at `time`.
+pub fn local_time(time: u64) -> (u32, u32) {
+    DateTime::from_timestamp(time as i64, 0)
+        .map(|time| {
+            let time = time.with_timezone(&Local);
+            (time.hour(), time.minute())
+        })
+        .unwrap_or_default()
+}
+
+/// Unix time at `hour`:`minute` on the local `date`.
+pub fn local_timestamp(date: NaiveDate, (hour, minute): (u32, u32)) -> Option<u64> {
+    let time = date
+        .and_hms_opt(hour, minute, 0)?
+        .and_local_timezone(Local)
+        .earliest()?;
+    u64::try_from(time.timestamp()).ok()
+}
+
+/// Heading of a day, relative for the last two days.
+pub fn format_day(date: NaiveDate) -> String {
+    let today = today();
+    if date == today {
+        fl!("today")
+    } else if today.pred_opt() == Some(date) {
+        fl!("yesterday")
+    } else {
+        let weekdays = alarms::weekday_names();
+        fl!(
+            "day-date",
+            weekday = weekdays[date.weekday().num_days_from_monday() as usize].clone(),
+            day = date.day().to_string(),
+            month = month_name(date.month()),
+            year = date.year().to_string()
+        )
+    }
+}
+
+/// Short name of `month`, from 1 for January.
+fn month_name(month: u32) -> String {
+    match month {
+        1 => fl!("month-jan"),
+        2 => fl!("month-feb"),
+        3 => fl!("month-mar"),
+        4 => fl!("month-apr"),
+        5 => fl!("month-may"),
+        6 => fl!("month-jun"),
+        7 => fl!("month-jul"),
+        8 => fl!("month-aug"),
+        9 => fl!("month-sep"),
+        10 => fl!("month-oct"),
+        11 => fl!("month-nov"),
+        _ => fl!("month-dec"),
+    }
+}
+
+pub fn today() -> NaiveDate {
+    Local::now().date_naive()
+}
+
+/// Unix time of the first end-of-day summary shown at `hour`:`minute` after `after`.
+pub fn next_summary_at((hour, minute): (u32, u32), after: DateTime<Local>) -> Option<i64> {
+    let alarm = Alarm {
+        hour,
+        minute,
+        days: [true; 7],
+        enabled: true,
+        ..Default::default()
+    };
+    alarm.next_after(after).map(|at| at.timestamp())
+}
+
+fn state() -> Option<cosmic_config::Config> {
+    cosmic_config::Config::new_state(crate::app::AppModel::APP_ID, CONFIG_VERSION)
+        .map_err(|err| log::warn!("failed to open state config: {err}"))
+        .ok()
+}
+
+/// Entries saved by [`save`], oldest first, without those too old to keep.
+pub fn load() -> Vec<Entry> {
+    let mut entries = state()
+        .and_then(|state| state.get::<Vec<Entry>>(HISTORY_KEY).ok())
+        .unwrap_or_default();
+    prune(&mut entries, today());
+    entries
+}
+
+/// Drops the entries that ended more than a year before `today`.
+pub fn prune(entries: &mut Vec<Entry>, today: NaiveDate) {
+    let Some(oldest) = today.checked_sub_days(Days::new(KEEP_DAYS)) else {
+        return;
+    };
+    let old = entries.partition_point(|entry| entry.date() < oldest);
+    if old > 0 {
+        log::info!("dropping {old} history entries older than a year");
+        entries.drain(..old);
+    }
+}
+
+pub fn save(entries: &[Entry]) {
+    if let Some(state) = state() {
+        if let Err(err) = state.set(HISTORY_KEY, entries) {
+            log::error!("failed to save history: {err}");
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(kind: EntryKind, date: NaiveDate, hour: u32, duration: u32) -> Entry {
+        let ended_at = local_timestamp(date, (hour, 0)).unwrap();
+        Entry {
+            kind,
+            started_at: ended_at - duration as u64,
+            ended_at,
+            duration,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn summarizes_a_day() {
+        let monday = NaiveDate::from_ymd_opt(2024, 1, 15).unwrap();
+        let mut interrupted = entry(EntryKind::Focus, monday, 10, 1500);
+        interrupted.task = " Write report ".into();
+        interrupted.interruptions = vec![
+            Interruption::default(),
+            Interruption {
+                kind: InterruptionKind::External,
+                ..Default::default()
+            },
+        ];
+        let mut review = entry(EntryKind::Focus, monday, 12, 1200);
+        review.task = "Review".into();
+        let mut again = entry(EntryKind::Focus, monday, 14, 600);
+        again.task = "Write report".into();
+        let entries = [
+            entry(EntryKind::Focus, monday.succ_opt().unwrap(), 10, 1500),
+            interrupted,
+            entry(EntryKind::Break, monday, 11, 300),
+            review,
+            again,
+        ];
+
+        let summary = DaySummary::new(&entries, monday);
+
+        assert_eq!(summary.focus_blocks, 3);
+        assert_eq!(summary.focus_seconds, 3300);
+        assert_eq!(summary.break_seconds, 300);
+        assert_eq!(summary.internal_interruptions, 1);
+        assert_eq!(summary.external_interruptions, 1);
+        assert_eq!(summary.interruptions(), 2);
+        assert_eq!(summary.tasks, ["Write report", "Review"]);
+        assert_eq!(summary.progress(GoalUnit::Pomodoros), 3);
+        assert_eq!(summary.progress(GoalUnit::Minutes), 55);
+        assert_eq!(summary.average_rating(), None);
+    }
+
+    #[test]
+    fn summarizes_a_day_without_entries() {
+        let monday = NaiveDate::from_ymd_opt(2024, 1, 15).unwrap();
+        let entries = [entry(EntryKind::Focus, monday, 10, 1500)];
+
+        let summary = DaySummary::new(&entries, monday.succ_opt().unwrap());
+
+        assert_eq!(summary, DaySummary::default());
+    }
+
+    #[test]
+    fn summarizes_each_day_since() {
+        let monday = NaiveDate::from_ymd_opt(2024, 1, 15).unwrap();
+        let tuesday = monday.succ_opt().unwrap();
+        let mut rated = entry(EntryKind::Focus, tuesday, 10, 1500);
+        rated.rating = Some(4);
+        rated.interruptions.push(Interruption {
+            kind: InterruptionKind::External,
+            ..Default::default()
+        });
+        let entries = [
+            entry(EntryKind::Focus, monday.pred_opt().unwrap(), 10, 1500),
+            entry(EntryKind::Focus, monday, 10, 1500),
+            entry(EntryKind::Break, monday, 11, 300),
+            rated,
+            entry(EntryKind::Focus, tuesday, 11, 1200),
+        ];
+
+        let (total, days) = summarize_since(&entries, monday);
+
+        assert_eq!(total.focus_blocks, 3);
+        assert_eq!(total.focus_seconds, 4200);
+        assert_eq!(total.break_seconds, 300);
+        assert_eq!(total.external_interruptions, 1);
+        assert_eq!(total.average_rating(), Some(4.));
+        assert_eq!(days.keys().copied().collect::<Vec<_>>(), [monday, tuesday]);
+        assert_eq!(days[&monday], DaySummary::new(&entries, monday));
+        assert_eq!(days[&tuesday], DaySummary::new(&entries, tuesday));
+    }
+
+    #[test]
+    fn next_summary_is_due_until_shown() {
+        let monday = NaiveDate::from_ymd_opt(2024, 1, 15).unwrap();
+        let at = |date, time| {
+            DateTime::from_timestamp(local_timestamp(date, time).unwrap() as i64, 0)
+                .unwrap()
+                .with_timezone(&Local)
+        };
+        let today = at(monday, (18, 0)).timestamp();
+        let tomorrow = at(monday.succ_opt().unwrap(), (18, 0)).timestamp();
+
+        // Still today's while it hasn't been shown, however late it's asked
+        assert_eq!(next_summary_at((18, 0), at(monday, (9, 0))), Some(today));
+        assert_eq!(next_summary_at((18, 0), at(monday, (17, 59))), Some(today));
+        // Tomorrow's once shown
+        assert_eq!(
+            next_summary_at((18, 0), at(monday, (18, 0))),
+            Some(tomorrow)
+        );
+    }
+
+    #[test]
+    fn prunes_entries_older_than_a_year() {
+        let today = NaiveDate::from_ymd_opt(2025, 1, 15).unwrap();
+        let mut entries = vec![
+            entry(
+                EntryKind::Focus,
+                NaiveDate::from_ymd_opt(2024, 1, 14).unwrap(),
+                10,
+                1500,
+            ),
+            entry(
+                EntryKind::Focus,
+                NaiveDate::from_ymd_opt(2024, 1, 15).unwrap(),
+                10,
+                1500,
+            ),
+            entry(EntryKind::Focus, today, 10, 1500),
+        ];
+        let kept = entries[1..].to_vec();
+
+        prune(&mut entries, today);
+
+        assert_eq!(entries, kept);
+    }
+}
@@ -0,0 +1,62 @@
+// SPDX-License-Identifier: {{LICENSE}}
+
+//! Runs the user's hook executables on timer events.
+
+use std::process::Stdio;
+use std::time::Duration;
+
+use tokio::process::Command;
+
+use crate::core::timer::TimerEvent;
+
+/// Runs `program` with the event described in its environment.
+///
+/// The hook is killed once `timeout` elapses. Failures are only logged, a broken
+/// hook must never get in the way of the timer.
+pub async fn run(program: String, event: TimerEvent, timeout: Duration) {
+    let child = Command::new(&program)
+        .env("CHRONOS_EVENT", event.kind.id())
+        .env("CHRONOS_PHASE", event.phase.id())
+        .env("CHRONOS_DURATION", event.duration.to_string())
+        .env("CHRONOS_TASK", &event.task)
+        .env("CHRONOS_CYCLE", event.cycle.to_string())
+        .stdin(Stdio::null())
+        .kill_on_drop(true)
+        .spawn();
+
+    let mut child = match child {
+        Ok(child) => child,
+        Err(err) => {
+            log::error!("failed to run {} hook '{}': {}", event.kind, program, err);
+            return;
+        }
+    };
+
+    match tokio::time::timeout(timeout, child.wait()).await {
+        Ok(Ok(status)) if status.success() => {
+            log::debug!("{} hook '{}' finished", event.kind, program);
+        }
+        Ok(Ok(status)) => {
+            log::warn!("{} hook '{}' failed: {}", event.kind, program, status);
+        }
+        Ok(Err(err)) => {
+            log::error!(
+                "failed to wait for {} hook '{}': {}",
+                event.kind,
+                program,
+                err
+            );
+        }
+        Err(_) => {
+            log::warn!(
+                "{} hook '{}' timed out after {}s, killing it",
+                event.kind,
+                program,
+                timeout.as_secs()
+            );
+            if let Err(err) = child.kill().await {
+                log::error!("failed to kill {} hook '{}': {}", event.kind, program, err);
+            }
+        }
+    }
+}
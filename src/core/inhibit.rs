@@ -0,0 +1,244 @@
+// SPDX-License-Identifier: {{LICENSE}}
+
+//! Keeps the screen from blanking and the system from suspending during focus blocks.
+//!
+//! Both the `org.freedesktop.ScreenSaver` session service and logind are asked for an
+//! inhibitor, since desktops differ in which one they honor. The D-Bus calls happen
+//! on a worker thread so the interface never waits on them.
+
+use std::thread;
+
+use tokio::sync::mpsc;
+use zbus::zvariant::OwnedFd;
+use zbus::{proxy, Connection};
+
+const APP_NAME: &str = "Chronos";
+
+#[proxy(
+    interface = "org.freedesktop.ScreenSaver",
+    default_service = "org.freedesktop.ScreenSaver",
+    default_path = "/org/freedesktop/ScreenSaver"
+)]
+trait ScreenSaver {
+    fn inhibit(&self, application_name: &str, reason_for_inhibit: &str) -> zbus::Result<u32>;
+
+    fn un_inhibit(&self, cookie: u32) -> zbus::Result<()>;
+}
+
+#[proxy(
+    interface = "org.freedesktop.login1.Manager",
+    default_service = "org.freedesktop.login1",
+    default_path = "/org/freedesktop/login1"
+)]
+trait LoginManager {
+    fn inhibit(&self, what: &str, who: &str, why: &str, mode: &str) -> zbus::Result<OwnedFd>;
+}
+
+/// Connections to talk over, the user's session and system buses when unset.
+///
+/// Handing over peer connections lets the inhibitor run against mock services.
+#[derive(Clone, Debug, Default)]
+pub struct Buses {
+    pub session: Option<Connection>,
+    pub system: Option<Connection>,
+}
+
+/// Handle to the worker holding the inhibitors.
+///
+/// Dropping it releases anything still held.
+pub struct Inhibitor {
+    sender: mpsc::UnboundedSender<bool>,
+}
+
+impl Inhibitor {
+    pub fn new() -> Self {
+        Self::with_buses(Buses::default())
+    }
+
+    pub fn with_buses(buses: Buses) -> Self {
+        let (sender, receiver) = mpsc::unbounded_channel();
+
+        let spawned = thread::Builder::new()
+            .name("chronos-inhibit".into())
+            .spawn(move || {
+                let runtime = match tokio::runtime::Builder::new_current_thread()
+                    .enable_all()
+                    .build()
+                {
+                    Ok(runtime) => runtime,
+                    Err(err) => {
+                        log::error!("failed to start inhibitor runtime: {err}");
+                        return;
+                    }
+                };
+                runtime.block_on(worker(buses, receiver));
+            });
+        if let Err(err) = spawned {
+            log::error!("failed to start inhibitor thread: {err}");
+        }
+
+        Self { sender }
+    }
+
+    /// Takes the inhibitors, or releases them with `false`.
+    pub fn set(&self, inhibit: bool) {
+        // The worker only stops once this handle is dropped.
+        let _ = self.sender.send(inhibit);
+    }
+}
+
+#[derive(Default)]
+struct Held {
+    screensaver: Option<(ScreenSaverProxy<'static>, u32)>,
+    /// logind releases the inhibitor once this descriptor is closed.
+    logind: Option<OwnedFd>,
+}
+
+async fn worker(buses: Buses, mut receiver: mpsc::UnboundedReceiver<bool>) {
+    let mut held: Option<Held> = None;
+
+    while let Some(inhibit) = receiver.recv().await {
+        match (inhibit, held.is_some()) {
+            (true, false) => held = Some(acquire(&buses).await),
+            (false, true) => {
+                if let Some(held) = held.take() {
+                    release(held).await;
+                }
+            }
+            _ => {}
+        }
+    }
+
+    if let Some(held) = held {
+        release(held).await;
+    }
+}
+
+async fn acquire(buses: &Buses) -> Held {
+    let mut held = Held::default();
+    let reason = "Focus block in progress";
+
+    match connect(buses.session.as_ref(), false).await {
+        Ok(connection) => match ScreenSaverProxy::new(&connection).await {
+            Ok(proxy) => match proxy.inhibit(APP_NAME, reason).await {
+                Ok(cookie) => held.screensaver = Some((proxy, cookie)),
+                Err(err) => log::warn!("screensaver inhibit failed: {err}"),
+            },
+            Err(err) => log::warn!("screensaver service unavailable: {err}"),
+        },
+        Err(err) => log::warn!("failed to connect to the session bus: {err}"),
+    }
+
+    match connect(buses.system.as_ref(), true).await {
+        Ok(connection) => match LoginManagerProxy::new(&connection).await {
+            Ok(proxy) => match proxy.inhibit("idle:sleep", APP_NAME, reason, "block").await {
+                Ok(fd) => held.logind = Some(fd),
+                Err(err) => log::warn!("logind inhibit failed: {err}"),
+            },
+            Err(err) => log::warn!("logind unavailable: {err}"),
+        },
+        Err(err) => log::warn!("failed to connect to the system bus: {err}"),
+    }
+
+    held
+}
+
+async fn release(held: Held) {
+    if let Some((proxy, cookie)) = held.screensaver {
+        if let Err(err) = proxy.un_inhibit(cookie).await {
+            log::warn!("screensaver uninhibit failed: {err}");
+        }
+    }
+    drop(held.logind);
+}
+
+async fn connect(connection: Option<&Connection>, system: bool) -> zbus::Result<Connection> {
+    match connection {
+        Some(connection) => Ok(connection.clone()),
+        None if system => Connection::system().await,
+        None => Connection::session().await,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{Arc, Mutex};
+    use std::time::Duration;
+
+    use tokio::net::UnixStream;
+    use zbus::{connection, interface, Guid};
+
+    use super::*;
+
+    /// Screen saver service recording the calls it receives.
+    #[derive(Clone, Default)]
+    struct MockScreenSaver {
+        calls: Arc<Mutex<Vec<String>>>,
+    }
+
+    #[interface(name = "org.freedesktop.ScreenSaver")]
+    impl MockScreenSaver {
+        fn inhibit(&self, application_name: &str, _reason_for_inhibit: &str) -> u32 {
+            self.calls
+                .lock()
+                .unwrap()
+                .push(format!("Inhibit {application_name}"));
+            42
+        }
+
+        fn un_inhibit(&self, cookie: u32) {
+            self.calls
+                .lock()
+                .unwrap()
+                .push(format!("UnInhibit {cookie}"));
+        }
+    }
+
+    /// Connects to a peer serving `service`.
+    async fn peer(service: MockScreenSaver) -> (Connection, Connection) {
+        let (client, server) = UnixStream::pair().unwrap();
+        let server = connection::Builder::unix_stream(server)
+            .server(Guid::generate())
+            .unwrap()
+            .p2p()
+            .serve_at("/org/freedesktop/ScreenSaver", service)
+            .unwrap();
+        let client = connection::Builder::unix_stream(client).p2p();
+        let (client, server) = tokio::join!(client.build(), server.build());
+        (client.unwrap(), server.unwrap())
+    }
+
+    async fn wait_for(calls: &Arc<Mutex<Vec<String>>>, count: usize) -> Vec<String> {
+        for _ in 0..100 {
+            if calls.lock().unwrap().len() >= count {
+                break;
+            }
+            tokio::time::sleep(Duration::from_millis(20)).await;
+        }
+        calls.lock().unwrap().clone()
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn inhibits_and_releases_the_screensaver() {
+        let screensaver = MockScreenSaver::default();
+        let (session, _session_server) = peer(screensaver.clone()).await;
+        // No logind on the other end, its inhibitor is just skipped
+        let (system, _system_server) = peer(MockScreenSaver::default()).await;
+
+        let inhibitor = Inhibitor::with_buses(Buses {
+            session: Some(session),
+            system: Some(system),
+        });
+
+        inhibitor.set(true);
+        assert_eq!(wait_for(&screensaver.calls, 1).await, ["Inhibit Chronos"]);
+
+        // Already held, nothing more to ask for
+        inhibitor.set(true);
+        inhibitor.set(false);
+        assert_eq!(
+            wait_for(&screensaver.calls, 2).await,
+            ["Inhibit Chronos", "UnInhibit 42"]
+        );
+    }
+}
@@ -1 +1,16 @@
-pub mod nav;
\ No newline at end of file
+pub mod alarms;
+pub mod ambience;
+pub mod cli;
+pub mod countdowns;
+pub mod dnd;
+pub mod history;
+pub mod hooks;
+pub mod inhibit;
+pub mod nav;
+pub mod presence;
+pub mod shortcuts;
+pub mod sound;
+pub mod status;
+pub mod timer;
+pub mod tz;
+pub mod webhooks;
\ No newline at end of file
@@ -1,11 +1,19 @@
 use cosmic::{widget::icon, Element};
+use serde::{Deserialize, Serialize};
 
 use crate::{app, fl};
 
-#[derive(Clone, Copy, Default, Debug, Eq, PartialEq)]
+#[derive(
+    Clone, Copy, Default, Debug, Eq, Hash, Ord, PartialEq, PartialOrd, Deserialize, Serialize,
+)]
 pub enum NavPage {
     #[default]
     Pomodoro,
+    Stopwatch,
+    Timers,
+    Alarms,
+    WorldClock,
+    Statistics,
     Settings,
 }
 
@@ -19,6 +27,11 @@ impl NavPage {
     pub fn title(&self) -> String {
         match self {
             Self::Pomodoro => fl!("pomodoro"),
+            Self::Stopwatch => fl!("stopwatch"),
+            Self::Timers => fl!("timers"),
+            Self::Alarms => fl!("alarms"),
+            Self::WorldClock => fl!("world-clock"),
+            Self::Statistics => fl!("statistics"),
             Self::Settings => fl!("settings"),
         }
     }
@@ -26,6 +39,11 @@ impl NavPage {
     pub fn icon(&self) -> cosmic::widget::Icon {
         match self {
             Self::Pomodoro => icon::from_name("process-working-spinner-hour-symbolic").into(),
+            Self::Stopwatch => icon::from_name("preferences-system-time-symbolic").into(),
+            Self::Timers => icon::from_name("x-office-calendar-symbolic").into(),
+            Self::Alarms => icon::from_name("alarm-symbolic").into(),
+            Self::WorldClock => icon::from_name("applications-internet-symbolic").into(),
+            Self::Statistics => icon::from_name("utilities-system-monitor-symbolic").into(),
             Self::Settings => icon::from_name("application-default-symbolic").into(),
         }
     }
@@ -33,11 +51,27 @@ impl NavPage {
     pub fn view<'a>(&self, app: &'a app::AppModel) -> Element<'a, app::Message> {
         match self {
             NavPage::Pomodoro => app.pomodoro.view().map(app::Message::Pomodoro),
+            NavPage::Stopwatch => app.stopwatch.view().map(app::Message::Stopwatch),
+            NavPage::Timers => app.timers.view().map(app::Message::Timers),
+            NavPage::Alarms => app.alarms.view().map(app::Message::Alarms),
+            NavPage::WorldClock => app.world_clock.view().map(app::Message::WorldClock),
+            NavPage::Statistics => app
+                .statistics
+                .view(app.pomodoro.history())
+                .map(app::Message::Statistics),
             NavPage::Settings => app.settings.view().map(app::Message::Settings),
         }
     }
 
     pub fn all() -> &'static [Self] {
-        &[Self::Pomodoro, Self::Settings]
+        &[
+            Self::Pomodoro,
+            Self::Stopwatch,
+            Self::Timers,
+            Self::Alarms,
+            Self::WorldClock,
+            Self::Statistics,
+            Self::Settings,
+        ]
     }
 }
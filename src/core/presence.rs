@@ -0,0 +1,105 @@
+// SPDX-License-Identifier: {{LICENSE}}
+
+//! Notices when the user leaves the computer: suspend, screen lock and idle.
+//!
+//! All three are reported by logind, suspend through the manager's `PrepareForSleep`
+//! signal and the others through the current session.
+
+use std::any::TypeId;
+
+use cosmic::iced::futures::channel::mpsc;
+use cosmic::iced::futures::{SinkExt, StreamExt};
+use cosmic::iced::{stream, Subscription};
+use zbus::{proxy, Connection};
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum AwayReason {
+    Suspend,
+    Lock,
+    Idle,
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum PresenceEvent {
+    Away(AwayReason),
+    Back(AwayReason),
+}
+
+#[proxy(
+    interface = "org.freedesktop.login1.Manager",
+    default_service = "org.freedesktop.login1",
+    default_path = "/org/freedesktop/login1"
+)]
+trait Manager {
+    #[zbus(name = "GetSessionByPID")]
+    fn get_session_by_pid(&self, pid: u32) -> zbus::Result<zbus::zvariant::OwnedObjectPath>;
+
+    #[zbus(signal)]
+    fn prepare_for_sleep(&self, start: bool) -> zbus::Result<()>;
+}
+
+#[proxy(
+    interface = "org.freedesktop.login1.Session",
+    default_service = "org.freedesktop.login1"
+)]
+trait Session {
+    #[zbus(signal)]
+    fn lock(&self) -> zbus::Result<()>;
+
+    #[zbus(signal)]
+    fn unlock(&self) -> zbus::Result<()>;
+
+    #[zbus(property)]
+    fn idle_hint(&self) -> zbus::Result<bool>;
+}
+
+/// Emits presence changes for as long as it is subscribed to.
+pub fn subscription() -> Subscription<PresenceEvent> {
+    struct PresenceSubscription;
+
+    Subscription::run_with_id(
+        TypeId::of::<PresenceSubscription>(),
+        stream::channel(4, |mut output| async move {
+            if let Err(err) = watch(&mut output).await {
+                log::warn!("not watching for lock, suspend and idle: {err}");
+            }
+            // Keep the subscription alive so it isn't restarted in a loop.
+            std::future::pending::<()>().await;
+        }),
+    )
+}
+
+async fn watch(output: &mut mpsc::Sender<PresenceEvent>) -> zbus::Result<()> {
+    let connection = Connection::system().await?;
+    let manager = ManagerProxy::new(&connection).await?;
+    let session_path = manager.get_session_by_pid(std::process::id()).await?;
+    let session = SessionProxy::builder(&connection)
+        .path(session_path)?
+        .build()
+        .await?;
+
+    let mut sleep = manager.receive_prepare_for_sleep().await?;
+    let mut lock = session.receive_lock().await?;
+    let mut unlock = session.receive_unlock().await?;
+    let mut idle = session.receive_idle_hint_changed().await;
+
+    loop {
+        let event = tokio::select! {
+            Some(signal) = sleep.next() => match signal.args()?.start {
+                true => PresenceEvent::Away(AwayReason::Suspend),
+                false => PresenceEvent::Back(AwayReason::Suspend),
+            },
+            Some(_) = lock.next() => PresenceEvent::Away(AwayReason::Lock),
+            Some(_) = unlock.next() => PresenceEvent::Back(AwayReason::Lock),
+            Some(change) = idle.next() => match change.get().await? {
+                true => PresenceEvent::Away(AwayReason::Idle),
+                false => PresenceEvent::Back(AwayReason::Idle),
+            },
+            else => return Ok(()),
+        };
+
+        if output.send(event).await.is_err() {
+            return Ok(());
+        }
+    }
+}
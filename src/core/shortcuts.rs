@@ -0,0 +1,174 @@
+// SPDX-License-Identifier: {{LICENSE}}
+
+//! Keyboard shortcuts for the timer and the window.
+//!
+//! Bindings are written as text like `Ctrl+Shift+S`, which is how they are stored
+//! in the config and edited in the settings.
+
+use std::collections::{BTreeMap, HashMap};
+
+use cosmic::iced::keyboard::key::Named;
+use cosmic::iced::keyboard::Key;
+use cosmic::widget::menu::key_bind::{KeyBind, Modifier};
+use serde::{Deserialize, Serialize};
+
+use crate::core::nav::NavPage;
+
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd, Deserialize, Serialize)]
+pub enum Shortcut {
+    StartPause,
+    Skip,
+    Reset,
+    Settings,
+    CompactMode,
+    /// Shows this page.
+    Page(NavPage),
+}
+
+impl Shortcut {
+    pub fn all() -> Vec<Self> {
+        let mut all = vec![
+            Self::StartPause,
+            Self::Skip,
+            Self::Reset,
+            Self::Settings,
+            Self::CompactMode,
+        ];
+        all.extend(NavPage::all().iter().copied().map(Self::Page));
+        all
+    }
+
+    pub fn default_binding(&self) -> String {
+        match self {
+            Self::StartPause => "Space".into(),
+            Self::Skip => "S".into(),
+            Self::Reset => "R".into(),
+            Self::Settings => "Ctrl+,".into(),
+            Self::CompactMode => "Ctrl+M".into(),
+            // Pages get the digit of their position, for the first nine
+            Self::Page(page) => NavPage::all()
+                .iter()
+                .position(|known| known == page)
+                .filter(|&index| index < 9)
+                .map(|index| format!("Ctrl+{}", index + 1))
+                .unwrap_or_default(),
+        }
+    }
+}
+
+/// Bindings for every shortcut, `overrides` taking precedence over the defaults.
+///
+/// An empty override removes the binding. Invalid ones, and ones already bound to an
+/// earlier shortcut, are logged and skipped.
+pub fn key_binds(overrides: &BTreeMap<Shortcut, String>) -> HashMap<KeyBind, Shortcut> {
+    let mut key_binds = HashMap::new();
+    for shortcut in Shortcut::all() {
+        let binding = overrides
+            .get(&shortcut)
+            .cloned()
+            .unwrap_or_else(|| shortcut.default_binding());
+        if binding.trim().is_empty() {
+            continue;
+        }
+        match parse(&binding) {
+            Some(key_bind) => match key_binds.get(&key_bind) {
+                Some(taken) => {
+                    log::warn!("binding '{binding}' for {shortcut:?} is taken by {taken:?}")
+                }
+                None => {
+                    key_binds.insert(key_bind, shortcut);
+                }
+            },
+            None => log::warn!("invalid binding '{binding}' for {shortcut:?}"),
+        }
+    }
+    key_binds
+}
+
+/// Parses a binding like `Ctrl+Shift+S`, the key coming last.
+pub fn parse(binding: &str) -> Option<KeyBind> {
+    let binding = binding.trim();
+    // `+` is a valid key on its own, as in `Ctrl++`
+    let (modifiers, key) = match binding.strip_suffix("++") {
+        Some(modifiers) => (modifiers, "+"),
+        None => match binding.rsplit_once('+') {
+            Some((modifiers, key)) => (modifiers, key),
+            None => ("", binding),
+        },
+    };
+
+    let modifiers = modifiers
+        .split('+')
+        .map(str::trim)
+        .filter(|modifier| !modifier.is_empty())
+        .map(|modifier| match modifier.to_lowercase().as_str() {
+            "ctrl" | "control" => Some(Modifier::Ctrl),
+            "shift" => Some(Modifier::Shift),
+            "alt" => Some(Modifier::Alt),
+            "super" | "logo" | "meta" => Some(Modifier::Super),
+            _ => None,
+        })
+        .collect::<Option<Vec<_>>>()?;
+
+    let key = key.trim();
+    let key = match key.to_lowercase().as_str() {
+        "space" => Key::Named(Named::Space),
+        "enter" | "return" => Key::Named(Named::Enter),
+        "escape" | "esc" => Key::Named(Named::Escape),
+        "tab" => Key::Named(Named::Tab),
+        "backspace" => Key::Named(Named::Backspace),
+        "delete" => Key::Named(Named::Delete),
+        "home" => Key::Named(Named::Home),
+        "end" => Key::Named(Named::End),
+        "pageup" => Key::Named(Named::PageUp),
+        "pagedown" => Key::Named(Named::PageDown),
+        "up" => Key::Named(Named::ArrowUp),
+        "down" => Key::Named(Named::ArrowDown),
+        "left" => Key::Named(Named::ArrowLeft),
+        "right" => Key::Named(Named::ArrowRight),
+        lower if lower.chars().count() == 1 => Key::Character(lower.into()),
+        _ => return None,
+    };
+
+    Some(KeyBind { modifiers, key })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_bindings() {
+        let key_bind = parse("Ctrl+Shift+s").unwrap();
+        assert_eq!(key_bind.modifiers, [Modifier::Ctrl, Modifier::Shift]);
+        assert_eq!(key_bind.key, Key::Character("s".into()));
+
+        assert_eq!(parse("Ctrl++").unwrap().key, Key::Character("+".into()));
+        assert_eq!(parse(" Space ").unwrap().key, Key::Named(Named::Space));
+        assert!(parse("Hyper+S").is_none());
+        assert!(parse("Ctrl+Nope").is_none());
+    }
+
+    #[test]
+    fn pages_keep_their_default_digit() {
+        assert_eq!(
+            Shortcut::Page(NavPage::Pomodoro).default_binding(),
+            "Ctrl+1"
+        );
+        assert_eq!(
+            Shortcut::Page(NavPage::Settings).default_binding(),
+            "Ctrl+7"
+        );
+    }
+
+    #[test]
+    fn rejects_bindings_already_taken() {
+        let overrides = BTreeMap::from([(Shortcut::Reset, "S".to_string())]);
+        let key_binds = key_binds(&overrides);
+
+        assert_eq!(key_binds.get(&parse("S").unwrap()), Some(&Shortcut::Skip));
+        assert!(!key_binds
+            .values()
+            .any(|&shortcut| shortcut == Shortcut::Reset));
+    }
+}
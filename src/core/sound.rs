@@ -0,0 +1,191 @@
+// SPDX-License-Identifier: {{LICENSE}}
+
+//! Sound alerts played when a phase ends.
+//!
+//! Playback goes through an [`AudioSink`], so the rest of the application doesn't
+//! care whether sounds reach the speakers or a [`NullSink`].
+
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+use std::sync::{Arc, Mutex};
+use std::thread;
+
+const FOCUS_END: &[u8] = include_bytes!("../../res/sounds/focus-end.wav");
+const BREAK_END: &[u8] = include_bytes!("../../res/sounds/break-end.wav");
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Sound {
+    FocusEnd,
+    BreakEnd,
+}
+
+impl Sound {
+    fn bundled(&self) -> (&'static str, &'static [u8]) {
+        match self {
+            Self::FocusEnd => ("focus-end.wav", FOCUS_END),
+            Self::BreakEnd => ("break-end.wav", BREAK_END),
+        }
+    }
+}
+
+/// Something able to play a sound file.
+pub trait AudioSink: Send + Sync {
+    /// Starts playing `path` at `volume`, between 0 and 100, without waiting for it.
+    fn play(&self, path: &Path, volume: u32) -> io::Result<()>;
+}
+
+/// Plays sounds with the PipeWire or PulseAudio command line players.
+pub struct CommandSink;
+
+impl AudioSink for CommandSink {
+    fn play(&self, path: &Path, volume: u32) -> io::Result<()> {
+        let volume = volume.min(100);
+        let players = [
+            ("pw-play", format!("--volume={}", volume as f32 / 100.)),
+            ("paplay", format!("--volume={}", volume * 65536 / 100)),
+        ];
+
+        // Use the first player found, `spawn` only fails when it is missing.
+        let mut last_err = io::Error::from(io::ErrorKind::NotFound);
+        for (program, volume_arg) in players {
+            let child = Command::new(program)
+                .arg(volume_arg)
+                .arg(path)
+                .stdout(Stdio::null())
+                .stderr(Stdio::null())
+                .spawn();
+            match child {
+                Ok(mut child) => {
+                    thread::spawn(move || child.wait());
+                    return Ok(());
+                }
+                Err(err) => last_err = err,
+            }
+        }
+        Err(last_err)
+    }
+}
+
+/// Discards every sound, remembering what it was asked to play.
+#[derive(Default)]
+pub struct NullSink {
+    pub played: Mutex<Vec<(PathBuf, u32)>>,
+}
+
+impl AudioSink for NullSink {
+    fn play(&self, path: &Path, volume: u32) -> io::Result<()> {
+        if let Ok(mut played) = self.played.lock() {
+            played.push((path.to_owned(), volume));
+        }
+        Ok(())
+    }
+}
+
+pub struct SoundPlayer {
+    sink: Arc<dyn AudioSink>,
+    /// Where the bundled sounds are extracted, since players need a file.
+    sounds_dir: PathBuf,
+}
+
+impl Default for SoundPlayer {
+    /// Plays through [`CommandSink`], or a [`NullSink`] with `CHRONOS_AUDIO_SINK=null`.
+    fn default() -> Self {
+        match std::env::var("CHRONOS_AUDIO_SINK").as_deref() {
+            Ok("null") => Self::new(Arc::new(NullSink::default()), sounds_dir()),
+            _ => Self::new(Arc::new(CommandSink), sounds_dir()),
+        }
+    }
+}
+
+impl SoundPlayer {
+    pub fn new(sink: Arc<dyn AudioSink>, sounds_dir: PathBuf) -> Self {
+        Self { sink, sounds_dir }
+    }
+
+    /// Plays the user's `file`, or the bundled sound when it is empty.
+    pub fn play(&self, sound: Sound, file: &str, volume: u32) {
+        let path = if file.is_empty() {
+            match bundled_path(sound, &self.sounds_dir) {
+                Ok(path) => path,
+                Err(err) => {
+                    log::error!("failed to extract bundled sound: {err}");
+                    return;
+                }
+            }
+        } else {
+            PathBuf::from(file)
+        };
+
+        if let Err(err) = self.sink.play(&path, volume) {
+            log::error!("failed to play '{}': {}", path.display(), err);
+        }
+    }
+}
+
+/// The user's cache directory for the bundled sounds.
+fn sounds_dir() -> PathBuf {
+    std::env::var_os("XDG_CACHE_HOME")
+        .map(PathBuf::from)
+        .or_else(|| std::env::var_os("HOME").map(|home| PathBuf::from(home).join(".cache")))
+        .unwrap_or_else(std::env::temp_dir)
+        .join("chronos")
+        .join("sounds")
+}
+
+/// Extracts a bundled sound to `dir`, unless it already is.
+fn bundled_path(sound: Sound, dir: &Path) -> io::Result<PathBuf> {
+    let (name, bytes) = sound.bundled();
+    let path = dir.join(name);
+
+    if fs::metadata(&path).map(|meta| meta.len()).ok() != Some(bytes.len() as u64) {
+        fs::create_dir_all(dir)?;
+        fs::write(&path, bytes)?;
+    }
+    Ok(path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn player(sounds_dir: PathBuf) -> (SoundPlayer, Arc<NullSink>) {
+        let sink = Arc::new(NullSink::default());
+        (SoundPlayer::new(sink.clone(), sounds_dir), sink)
+    }
+
+    #[test]
+    fn plays_the_bundled_sounds() {
+        let dir = std::env::temp_dir().join(format!("chronos-sounds-{}", std::process::id()));
+        let (player, sink) = player(dir.clone());
+        player.play(Sound::FocusEnd, "", 80);
+        player.play(Sound::BreakEnd, "", 30);
+
+        let played = sink.played.lock().unwrap();
+        assert_eq!(played.len(), 2);
+        assert!(played[0].0.ends_with("focus-end.wav"));
+        assert_eq!(played[0].1, 80);
+        assert!(played[1].0.ends_with("break-end.wav"));
+        assert_eq!(played[1].1, 30);
+        assert_eq!(fs::read(&played[0].0).unwrap(), FOCUS_END);
+        assert_eq!(fs::read(&played[1].0).unwrap(), BREAK_END);
+        assert!(played.iter().all(|(path, _)| path.starts_with(&dir)));
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn plays_the_chosen_file() {
+        let (player, sink) = player(PathBuf::from("/nonexistent"));
+        player.play(Sound::FocusEnd, "/sounds/bell.ogg", 100);
+        player.play(Sound::BreakEnd, "/sounds/gong.ogg", 0);
+
+        assert_eq!(
+            *sink.played.lock().unwrap(),
+            [
+                (PathBuf::from("/sounds/bell.ogg"), 100),
+                (PathBuf::from("/sounds/gong.ogg"), 0),
+            ]
+        );
+    }
+}
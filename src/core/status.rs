@@ -0,0 +1,237 @@
+// SPDX-License-Identifier: {{LICENSE}}
+
+//! Timer status shared with status bars and shell prompts.
+//!
+//! The running application keeps a small JSON file in the user's runtime directory
+//! up to date, which `chronos status` reads and formats.
+
+use std::fs;
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use serde::{Deserialize, Serialize};
+
+use crate::core::timer::{format_duration, Phase};
+
+pub const DEFAULT_FORMAT: &str = "{phase} {remaining}";
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct Status {
+    /// Process id of the application which wrote the status.
+    pub pid: u32,
+    pub phase: Phase,
+    pub running: bool,
+    /// Seconds left in the phase when the status was written, or spent in it
+    /// when counting up.
+    pub remaining: u32,
+    /// Length of the phase in seconds.
+    pub duration: u32,
+    pub cycle: u32,
+    pub cycles: u32,
+    pub task: String,
+    /// Set during a flowtime focus block, which has no set length.
+    #[serde(default)]
+    pub counting_up: bool,
+    /// Unix time at which the status was written.
+    pub updated_at: u64,
+}
+
+impl Status {
+    pub fn now() -> u64 {
+        SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|elapsed| elapsed.as_secs())
+            .unwrap_or_default()
+    }
+
+    /// Seconds left in the phase at the current time, or spent in it when counting up.
+    pub fn remaining_now(&self) -> u32 {
+        if !self.running {
+            return self.remaining;
+        }
+        let elapsed = Self::now().saturating_sub(self.updated_at) as u32;
+        if self.counting_up {
+            self.remaining + elapsed
+        } else {
+            self.remaining.saturating_sub(elapsed)
+        }
+    }
+
+    pub fn progress(&self) -> u32 {
+        if self.duration == 0 {
+            return 0;
+        }
+        (self.duration - self.remaining_now().min(self.duration)) * 100 / self.duration
+    }
+
+    pub fn state(&self) -> &'static str {
+        if self.running {
+            "running"
+        } else {
+            "paused"
+        }
+    }
+
+    /// Writes the status for other processes to read.
+    pub fn save(&self) -> io::Result<()> {
+        let path = path();
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        // Write to a temporary file first so readers never see a partial status.
+        let tmp = path.with_extension("json.tmp");
+        fs::write(&tmp, serde_json::to_vec(self)?)?;
+        fs::rename(tmp, path)
+    }
+
+    /// Reads the status of the running application, if there is one.
+    pub fn load() -> Option<Self> {
+        let status: Self = serde_json::from_slice(&fs::read(path()).ok()?).ok()?;
+        Path::new(&format!("/proc/{}", status.pid))
+            .exists()
+            .then_some(status)
+    }
+
+    /// Fills the `{placeholders}` of `template`.
+    pub fn format(&self, template: &str) -> String {
+        let remaining = self.remaining_now();
+        template
+            .replace("{phase}", self.phase.id())
+            .replace("{state}", self.state())
+            .replace("{remaining}", &format_duration(remaining))
+            .replace("{remaining_seconds}", &remaining.to_string())
+            .replace("{progress}", &self.progress().to_string())
+            .replace("{cycle}", &self.cycle.to_string())
+            .replace("{cycles}", &self.cycles.to_string())
+            .replace("{task}", &self.task)
+    }
+
+    /// A single line of JSON, usable directly by Waybar's custom modules.
+    pub fn to_json(&self, template: &str) -> serde_json::Value {
+        let remaining = self.remaining_now();
+        serde_json::json!({
+            "text": self.format(template),
+            "tooltip": self.task,
+            "class": self.phase.id(),
+            "percentage": self.progress(),
+            "phase": self.phase.id(),
+            "state": self.state(),
+            "remaining": format_duration(remaining),
+            "remaining_seconds": remaining,
+            "progress": self.progress(),
+            "cycle": self.cycle,
+            "cycles": self.cycles,
+            "task": self.task,
+        })
+    }
+}
+
+fn path() -> PathBuf {
+    std::env::var_os("XDG_RUNTIME_DIR")
+        .map(PathBuf::from)
+        .unwrap_or_else(std::env::temp_dir)
+        .join("chronos")
+        .join("status.json")
+}
+
+/// Options of the `chronos status` command.
+#[derive(Debug, Default)]
+pub struct StatusOptions {
+    pub format: Option<String>,
+    pub follow: bool,
+}
+
+/// Prints the status once, or a JSON object per second with `follow`.
+///
+/// The template fills the `text` field of the JSON objects.
+pub fn run(options: StatusOptions) -> io::Result<()> {
+    let mut stdout = io::stdout().lock();
+    let template = options.format.as_deref().unwrap_or(DEFAULT_FORMAT);
+
+    if !options.follow {
+        return match Status::load() {
+            Some(status) => writeln!(stdout, "{}", status.format(template)),
+            None => Err(io::Error::new(
+                io::ErrorKind::NotFound,
+                "Chronos is not running",
+            )),
+        };
+    }
+
+    loop {
+        let line = match Status::load() {
+            Some(status) => status.to_json(template),
+            None => serde_json::json!({ "text": "", "class": "stopped", "state": "stopped" }),
+        };
+        writeln!(stdout, "{line}")?;
+        stdout.flush()?;
+        std::thread::sleep(Duration::from_secs(1));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn status() -> Status {
+        Status {
+            pid: 1,
+            phase: Phase::Focus,
+            running: false,
+            remaining: 300,
+            duration: 1500,
+            cycle: 2,
+            cycles: 4,
+            task: "Write report".into(),
+            counting_up: false,
+            updated_at: Status::now(),
+        }
+    }
+
+    #[test]
+    fn fills_every_placeholder() {
+        assert_eq!(
+            status().format(
+                "{phase} {state} {remaining} {remaining_seconds}s {progress}% {cycle}/{cycles} {task}"
+            ),
+            "focus paused 00:05:00 300s 80% 2/4 Write report"
+        );
+        assert_eq!(status().format("no placeholders"), "no placeholders");
+    }
+
+    #[test]
+    fn counts_the_time_since_it_was_written() {
+        let mut status = Status {
+            running: true,
+            updated_at: Status::now() - 60,
+            ..status()
+        };
+        // A second may tick over while the test runs
+        assert!((239..=240).contains(&status.remaining_now()));
+        assert_eq!(status.state(), "running");
+
+        status.counting_up = true;
+        assert!((360..=361).contains(&status.remaining_now()));
+
+        status.running = false;
+        assert_eq!(status.remaining_now(), 300);
+    }
+
+    #[test]
+    fn progress_of_a_phase_without_length() {
+        let status = Status {
+            duration: 0,
+            ..status()
+        };
+        assert_eq!(status.progress(), 0);
+        assert_eq!(status.to_json("{phase}")["percentage"], 0);
+    }
+
+    #[test]
+    fn round_trips_through_json() {
+        let json = serde_json::to_vec(&status()).unwrap();
+        let status: Status = serde_json::from_slice(&json).unwrap();
+        assert_eq!(status.format("{task} {remaining}"), "Write report 00:05:00");
+    }
+}
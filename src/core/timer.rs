@@ -0,0 +1,167 @@
+// SPDX-License-Identifier: {{LICENSE}}
+
+//! Phases of the pomodoro cycle and the events emitted when moving between them.
+
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+use crate::fl;
+
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Deserialize, Serialize)]
+pub enum Phase {
+    #[default]
+    Focus,
+    ShortBreak,
+    LongBreak,
+}
+
+impl Phase {
+    pub fn is_break(&self) -> bool {
+        !matches!(self, Self::Focus)
+    }
+
+    pub fn start_event(&self) -> TimerEventKind {
+        if self.is_break() {
+            TimerEventKind::BreakStart
+        } else {
+            TimerEventKind::FocusStart
+        }
+    }
+
+    pub fn end_event(&self) -> TimerEventKind {
+        if self.is_break() {
+            TimerEventKind::BreakEnd
+        } else {
+            TimerEventKind::FocusEnd
+        }
+    }
+
+    /// Stable identifier used when the phase leaves the application.
+    pub fn id(&self) -> &'static str {
+        match self {
+            Self::Focus => "focus",
+            Self::ShortBreak => "short-break",
+            Self::LongBreak => "long-break",
+        }
+    }
+
+    pub fn label(&self) -> String {
+        match self {
+            Self::Focus => fl!("phase-focus"),
+            Self::ShortBreak => fl!("phase-short-break"),
+            Self::LongBreak => fl!("phase-long-break"),
+        }
+    }
+}
+
+/// How focus blocks end.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Deserialize, Serialize)]
+pub enum TimerMode {
+    /// Focus blocks and breaks of fixed length.
+    #[default]
+    Pomodoro,
+    /// Focus counts up until stopped, then a break proportional to it follows.
+    Flowtime,
+}
+
+impl TimerMode {
+    pub fn label(&self) -> String {
+        match self {
+            Self::Pomodoro => fl!("mode-pomodoro"),
+            Self::Flowtime => fl!("mode-flowtime"),
+        }
+    }
+}
+
+/// Whether the timer is counting down, independently of the phase.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum RunState {
+    /// Not started since the last reset.
+    #[default]
+    Stopped,
+    Running,
+    Paused,
+}
+
+impl RunState {
+    pub fn label(&self) -> String {
+        match self {
+            Self::Stopped => fl!("state-stopped"),
+            Self::Running => fl!("state-running"),
+            Self::Paused => fl!("state-paused"),
+        }
+    }
+
+    pub fn icon_name(&self) -> &'static str {
+        match self {
+            Self::Stopped => "media-playback-stop-symbolic",
+            Self::Running => "media-playback-start-symbolic",
+            Self::Paused => "media-playback-pause-symbolic",
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash, Deserialize, Serialize)]
+pub enum TimerEventKind {
+    FocusStart,
+    FocusEnd,
+    BreakStart,
+    BreakEnd,
+    Paused,
+    Reset,
+}
+
+impl TimerEventKind {
+    pub fn all() -> &'static [Self] {
+        &[
+            Self::FocusStart,
+            Self::FocusEnd,
+            Self::BreakStart,
+            Self::BreakEnd,
+            Self::Paused,
+            Self::Reset,
+        ]
+    }
+
+    /// Stable identifier used when the event leaves the application.
+    pub fn id(&self) -> &'static str {
+        match self {
+            Self::FocusStart => "focus-start",
+            Self::FocusEnd => "focus-end",
+            Self::BreakStart => "break-start",
+            Self::BreakEnd => "break-end",
+            Self::Paused => "paused",
+            Self::Reset => "reset",
+        }
+    }
+}
+
+impl fmt::Display for TimerEventKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.id())
+    }
+}
+
+/// A transition of the pomodoro timer, along with the state it happened in.
+#[derive(Clone, Debug)]
+pub struct TimerEvent {
+    pub kind: TimerEventKind,
+    /// The phase the event belongs to: the one ending for `*End` events, the one
+    /// starting for `*Start` events and the current one otherwise.
+    pub phase: Phase,
+    /// Length of `phase` in seconds.
+    pub duration: u32,
+    pub task: String,
+    /// Number of pomodoros completed since the last long break.
+    pub cycle: u32,
+}
+
+/// Formats a number of seconds as `HH:MM:SS`.
+pub fn format_duration(seconds: u32) -> String {
+    let hours = seconds / 3600;
+    let minutes = (seconds % 3600) / 60;
+    let remaining_seconds = seconds % 60;
+
+    format!("{:02}:{:02}:{:02}", hours, minutes, remaining_seconds)
+}
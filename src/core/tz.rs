@@ -0,0 +1,750 @@
+// SPDX-License-Identifier: {{LICENSE}}
+
+//! Time zones read from the system's tz database.
+//!
+//! Zone files use the TZif format described in RFC 8536. Times after the last
+//! transition listed in a file follow the POSIX TZ rule found in its footer.
+
+use std::fs;
+use std::io;
+use std::path::PathBuf;
+
+use chrono::{DateTime, Datelike, NaiveDate, Weekday};
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct LocalType {
+    /// Seconds east of UTC.
+    pub offset: i32,
+    pub dst: bool,
+    pub abbreviation: String,
+}
+
+#[derive(Clone, Debug)]
+pub struct TimeZone {
+    pub name: String,
+    /// Transition times, with the index of the local type starting at each.
+    transitions: Vec<(i64, usize)>,
+    types: Vec<LocalType>,
+    rule: Option<Rule>,
+}
+
+impl TimeZone {
+    /// Reads `name`, an IANA zone like `Europe/Rome`, from the tz database.
+    pub fn load(name: &str) -> io::Result<Self> {
+        // Zone names must not escape the database
+        if name.is_empty() || name.starts_with('/') || name.split('/').any(|part| part == "..") {
+            return Err(io::ErrorKind::InvalidInput.into());
+        }
+        let data = fs::read(database_dir().join(name))?;
+        Self::parse(name, &data).ok_or_else(|| io::ErrorKind::InvalidData.into())
+    }
+
+    fn parse(name: &str, data: &[u8]) -> Option<Self> {
+        let mut reader = Reader { data, position: 0 };
+        let (version, counts) = reader.header()?;
+
+        // Version 1 files only have 32 bit times, newer ones repeat the data with
+        // 64 bit times after the first block, followed by the rule.
+        if version >= b'2' {
+            reader.skip(counts.block_len(4))?;
+            let (_, counts) = reader.header()?;
+            return Self::parse_block(name, &mut reader, &counts, 8, true);
+        }
+        Self::parse_block(name, &mut reader, &counts, 4, false)
+    }
+
+    fn parse_block(
+        name: &str,
+        reader: &mut Reader,
+        counts: &Counts,
+        time_size: usize,
+        footer: bool,
+    ) -> Option<Self> {
+        let mut times = Vec::with_capacity(counts.time);
+        for _ in 0..counts.time {
+            times.push(if time_size == 8 {
+                reader.i64()?
+            } else {
+                reader.i32()? as i64
+            });
+        }
+        let mut indices = Vec::with_capacity(counts.time);
+        for _ in 0..counts.time {
+            indices.push(reader.u8()? as usize);
+        }
+        let mut raw_types = Vec::with_capacity(counts.types);
+        for _ in 0..counts.types {
+            raw_types.push((reader.i32()?, reader.u8()? != 0, reader.u8()? as usize));
+        }
+        let chars = reader.bytes(counts.chars)?;
+        reader.skip(counts.leap * (time_size + 4) + counts.is_std + counts.is_ut)?;
+
+        let types = raw_types
+            .into_iter()
+            .map(|(offset, dst, index)| {
+                let abbreviation = chars.get(index..)?;
+                let end = abbreviation.iter().position(|&c| c == 0)?;
+                Some(LocalType {
+                    offset,
+                    dst,
+                    abbreviation: String::from_utf8_lossy(&abbreviation[..end]).into_owned(),
+                })
+            })
+            .collect::<Option<Vec<_>>>()?;
+        if types.is_empty() || indices.iter().any(|&index| index >= types.len()) {
+            return None;
+        }
+
+        let rule = if footer {
+            let rest = reader.rest();
+            let rest = rest.strip_prefix(b"\n")?;
+            let end = rest.iter().position(|&c| c == b'\n')?;
+            Rule::parse(std::str::from_utf8(&rest[..end]).ok()?)
+        } else {
+            None
+        };
+
+        Some(Self {
+            name: name.to_string(),
+            transitions: times.into_iter().zip(indices).collect(),
+            types,
+            rule,
+        })
+    }
+
+    /// The local time type in effect at `time`, a Unix time.
+    pub fn local_type(&self, time: i64) -> LocalType {
+        let after_last = self
+            .transitions
+            .last()
+            .is_none_or(|&(last, _)| time >= last);
+        if after_last {
+            if let Some(rule) = &self.rule {
+                return rule.local_type(time);
+            }
+        }
+
+        match self.transitions.partition_point(|&(at, _)| at <= time) {
+            // Before the first transition the first type applies
+            0 => self.types[0].clone(),
+            index => self.types[self.transitions[index - 1].1].clone(),
+        }
+    }
+}
+
+/// Directory of the tz database, `$TZDIR` when set.
+fn database_dir() -> PathBuf {
+    std::env::var_os("TZDIR")
+        .map(PathBuf::from)
+        .unwrap_or_else(|| PathBuf::from("/usr/share/zoneinfo"))
+}
+
+/// Names of the zones in the tz database, sorted.
+pub fn zone_names() -> Vec<String> {
+    let dir = database_dir();
+    let table = fs::read_to_string(dir.join("zone1970.tab"))
+        .or_else(|_| fs::read_to_string(dir.join("zone.tab")))
+        .unwrap_or_default();
+
+    let mut names: Vec<String> = table
+        .lines()
+        .filter(|line| !line.starts_with('#'))
+        .filter_map(|line| line.split('\t').nth(2))
+        .map(str::to_string)
+        .collect();
+    names.push("UTC".into());
+    names.sort();
+    names.dedup();
+    names
+}
+
+struct Counts {
+    is_ut: usize,
+    is_std: usize,
+    leap: usize,
+    time: usize,
+    types: usize,
+    chars: usize,
+}
+
+impl Counts {
+    /// Length of the data block following the header.
+    fn block_len(&self, time_size: usize) -> usize {
+        self.time * (time_size + 1)
+            + self.types * 6
+            + self.chars
+            + self.leap * (time_size + 4)
+            + self.is_std
+            + self.is_ut
+    }
+}
+
+struct Reader<'a> {
+    data: &'a [u8],
+    position: usize,
+}
+
+impl<'a> Reader<'a> {
+    fn bytes(&mut self, len: usize) -> Option<&'a [u8]> {
+        let bytes = self
+            .data
+            .get(self.position..self.position.checked_add(len)?)?;
+        self.position += len;
+        Some(bytes)
+    }
+
+    fn skip(&mut self, len: usize) -> Option<()> {
+        self.bytes(len).map(|_| ())
+    }
+
+    fn rest(&self) -> &'a [u8] {
+        &self.data[self.position..]
+    }
+
+    fn u8(&mut self) -> Option<u8> {
+        Some(self.bytes(1)?[0])
+    }
+
+    fn i32(&mut self) -> Option<i32> {
+        Some(i32::from_be_bytes(self.bytes(4)?.try_into().ok()?))
+    }
+
+    fn i64(&mut self) -> Option<i64> {
+        Some(i64::from_be_bytes(self.bytes(8)?.try_into().ok()?))
+    }
+
+    fn header(&mut self) -> Option<(u8, Counts)> {
+        if self.bytes(4)? != b"TZif" {
+            return None;
+        }
+        let version = self.u8()?;
+        self.skip(15)?;
+        let mut count = || self.i32().and_then(|count| usize::try_from(count).ok());
+        let counts = Counts {
+            is_ut: count()?,
+            is_std: count()?,
+            leap: count()?,
+            time: count()?,
+            types: count()?,
+            chars: count()?,
+        };
+        Some((version, counts))
+    }
+}
+
+/// A POSIX TZ rule like `CET-1CEST,M3.5.0,M10.5.0/3`.
+#[derive(Clone, Debug)]
+struct Rule {
+    std: LocalType,
+    dst: Option<Dst>,
+}
+
+#[derive(Clone, Debug)]
+struct Dst {
+    local_type: LocalType,
+    start: RuleDate,
+    end: RuleDate,
+}
+
+#[derive(Clone, Copy, Debug)]
+enum Day {
+    /// `Jn`, from 1 to 365, February 29 is never counted.
+    Julian(u32),
+    /// `n`, from 0 to 365, counting February 29.
+    Ordinal(u32),
+    /// `Mm.w.d`, week 5 meaning the last one.
+    Month { month: u32, week: u32, weekday: u32 },
+}
+
+#[derive(Clone, Copy, Debug)]
+struct RuleDate {
+    day: Day,
+    /// Local time of day in seconds, possibly negative or past midnight.
+    time: i64,
+}
+
+impl Rule {
+    fn parse(rule: &str) -> Option<Self> {
+        let mut parser = RuleParser { rest: rule };
+        let std_name = parser.name()?;
+        // POSIX offsets are west of UTC
+        let std_offset = -parser.offset()?;
+        let std = LocalType {
+            offset: std_offset as i32,
+            dst: false,
+            abbreviation: std_name,
+        };
+        if parser.rest.is_empty() {
+            return Some(Self { std, dst: None });
+        }
+
+        let dst_name = parser.name()?;
+        let dst_offset = if parser.rest.starts_with(',') || parser.rest.is_empty() {
+            std_offset + 3600
+        } else {
+            -parser.offset()?
+        };
+        let (start, end) = if parser.rest.is_empty() {
+            // The historical default, the US rules
+            (
+                RuleDate {
+                    day: Day::Month {
+                        month: 3,
+                        week: 2,
+                        weekday: 0,
+                    },
+                    time: 7200,
+                },
+                RuleDate {
+                    day: Day::Month {
+                        month: 11,
+                        week: 1,
+                        weekday: 0,
+                    },
+                    time: 7200,
+                },
+            )
+        } else {
+            parser.expect(',')?;
+            let start = parser.date()?;
+            parser.expect(',')?;
+            let end = parser.date()?;
+            (start, end)
+        };
+        if !parser.rest.is_empty() {
+            return None;
+        }
+
+        Some(Self {
+            std,
+            dst: Some(Dst {
+                local_type: LocalType {
+                    offset: dst_offset as i32,
+                    dst: true,
+                    abbreviation: dst_name,
+                },
+                start,
+                end,
+            }),
+        })
+    }
+
+    fn local_type(&self, time: i64) -> LocalType {
+        let Some(dst) = &self.dst else {
+            return self.std.clone();
+        };
+        let Some(year) =
+            DateTime::from_timestamp(time + self.std.offset as i64, 0).map(|local| local.year())
+        else {
+            return self.std.clone();
+        };
+
+        // Daylight saving starts in standard time and ends in daylight time
+        let (Some(start), Some(end)) = (
+            dst.start.timestamp(year, self.std.offset),
+            dst.end.timestamp(year, dst.local_type.offset),
+        ) else {
+            return self.std.clone();
+        };
+        let in_dst = if start < end {
+            start <= time && time < end
+        } else {
+            // Southern hemisphere, daylight saving spans the new year
+            !(end <= time && time < start)
+        };
+
+        if in_dst {
+            dst.local_type.clone()
+        } else {
+            self.std.clone()
+        }
+    }
+}
+
+impl RuleDate {
+    /// Unix time of this date in `year`, for a zone `offset` seconds east of UTC.
+    fn timestamp(&self, year: i32, offset: i32) -> Option<i64> {
+        let date = match self.day {
+            Day::Julian(day) => {
+                let mut ordinal = day;
+                let leap = NaiveDate::from_ymd_opt(year, 2, 29).is_some();
+                if leap && day >= 60 {
+                    ordinal += 1;
+                }
+                NaiveDate::from_yo_opt(year, ordinal)?
+            }
+            Day::Ordinal(day) => NaiveDate::from_yo_opt(year, day + 1)?,
+            Day::Month {
+                month,
+                week,
+                weekday,
+            } => {
+                let weekday = [
+                    Weekday::Sun,
+                    Weekday::Mon,
+                    Weekday::Tue,
+                    Weekday::Wed,
+                    Weekday::Thu,
+                    Weekday::Fri,
+                    Weekday::Sat,
+                ][weekday as usize];
+                (1..=week as u8)
+                    .rev()
+                    .find_map(|n| NaiveDate::from_weekday_of_month_opt(year, month, weekday, n))?
+            }
+        };
+        let midnight = date.and_hms_opt(0, 0, 0)?.and_utc().timestamp();
+        Some(midnight + self.time - offset as i64)
+    }
+}
+
+struct RuleParser<'a> {
+    rest: &'a str,
+}
+
+impl RuleParser<'_> {
+    fn expect(&mut self, c: char) -> Option<()> {
+        self.rest = self.rest.strip_prefix(c)?;
+        Some(())
+    }
+
+    /// A zone abbreviation, alphabetic or quoted in angle brackets.
+    fn name(&mut self) -> Option<String> {
+        if let Some(quoted) = self.rest.strip_prefix('<') {
+            let end = quoted.find('>')?;
+            self.rest = &quoted[end + 1..];
+            return Some(quoted[..end].to_string());
+        }
+        let end = self
+            .rest
+            .find(|c: char| !c.is_ascii_alphabetic())
+            .unwrap_or(self.rest.len());
+        if end < 3 {
+            return None;
+        }
+        let name = self.rest[..end].to_string();
+        self.rest = &self.rest[end..];
+        Some(name)
+    }
+
+    fn number(&mut self) -> Option<i64> {
+        let end = self
+            .rest
+            .find(|c: char| !c.is_ascii_digit())
+            .unwrap_or(self.rest.len());
+        let number = self.rest[..end].parse().ok()?;
+        self.rest = &self.rest[end..];
+        Some(number)
+    }
+
+    /// `[+|-]hh[:mm[:ss]]` in seconds.
+    fn offset(&mut self) -> Option<i64> {
+        let sign = if let Some(rest) = self.rest.strip_prefix('-') {
+            self.rest = rest;
+            -1
+        } else {
+            if let Some(rest) = self.rest.strip_prefix('+') {
+                self.rest = rest;
+            }
+            1
+        };
+        let mut seconds = self.number()? * 3600;
+        if self.expect(':').is_some() {
+            seconds += self.number()? * 60;
+            if self.expect(':').is_some() {
+                seconds += self.number()?;
+            }
+        }
+        Some(sign * seconds)
+    }
+
+    fn date(&mut self) -> Option<RuleDate> {
+        let day = if self.expect('M').is_some() {
+            let month = self.number()? as u32;
+            self.expect('.')?;
+            let week = self.number()? as u32;
+            self.expect('.')?;
+            let weekday = self.number()? as u32;
+            if !(1..=12).contains(&month) || !(1..=5).contains(&week) || weekday > 6 {
+                return None;
+            }
+            Day::Month {
+                month,
+                week,
+                weekday,
+            }
+        } else if self.expect('J').is_some() {
+            let day = self.number()? as u32;
+            if !(1..=365).contains(&day) {
+                return None;
+            }
+            Day::Julian(day)
+        } else {
+            let day = self.number()? as u32;
+            if day > 365 {
+                return None;
+            }
+            Day::Ordinal(day)
+        };
+
+        let time = if self.expect('/').is_some() {
+            self.offset()?
+        } else {
+            7200
+        };
+        Some(RuleDate { day, time })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Zone files copied from the tz database, so results don't depend on the system.
+    fn zone(name: &str) -> TimeZone {
+        let data: &[u8] = match name {
+            "Europe/Rome" => include_bytes!("../../tests/fixtures/zoneinfo/Europe/Rome"),
+            "America/Sao_Paulo" => {
+                include_bytes!("../../tests/fixtures/zoneinfo/America/Sao_Paulo")
+            }
+            "Australia/Sydney" => include_bytes!("../../tests/fixtures/zoneinfo/Australia/Sydney"),
+            "Asia/Kolkata" => include_bytes!("../../tests/fixtures/zoneinfo/Asia/Kolkata"),
+            "UTC" => include_bytes!("../../tests/fixtures/zoneinfo/UTC"),
+            _ => unreachable!(),
+        };
+        TimeZone::parse(name, data).unwrap()
+    }
+
+    /// Unix time of a UTC date and time.
+    fn utc(year: i32, month: u32, day: u32, hour: u32, minute: u32, second: u32) -> i64 {
+        NaiveDate::from_ymd_opt(year, month, day)
+            .and_then(|date| date.and_hms_opt(hour, minute, second))
+            .unwrap()
+            .and_utc()
+            .timestamp()
+    }
+
+    fn offset(zone: &TimeZone, time: i64) -> (i32, bool, String) {
+        let local_type = zone.local_type(time);
+        (local_type.offset, local_type.dst, local_type.abbreviation)
+    }
+
+    fn local(offset: i32, dst: bool, abbreviation: &str) -> (i32, bool, String) {
+        (offset, dst, abbreviation.to_string())
+    }
+
+    #[test]
+    fn rome() {
+        let rome = zone("Europe/Rome");
+        assert_eq!(
+            offset(&rome, utc(2024, 1, 15, 12, 0, 0)),
+            local(3600, false, "CET")
+        );
+        assert_eq!(
+            offset(&rome, utc(2024, 3, 31, 0, 59, 59)),
+            local(3600, false, "CET")
+        );
+        assert_eq!(
+            offset(&rome, utc(2024, 3, 31, 1, 0, 0)),
+            local(7200, true, "CEST")
+        );
+        assert_eq!(
+            offset(&rome, utc(2023, 10, 29, 0, 59, 59)),
+            local(7200, true, "CEST")
+        );
+        assert_eq!(
+            offset(&rome, utc(2023, 10, 29, 1, 0, 0)),
+            local(3600, false, "CET")
+        );
+    }
+
+    #[test]
+    fn rome_after_the_last_transition() {
+        let rome = zone("Europe/Rome");
+        let time = utc(2099, 3, 29, 1, 0, 0);
+        assert!(rome
+            .transitions
+            .last()
+            .is_some_and(|&(last, _)| last < time));
+
+        assert_eq!(offset(&rome, time - 1), local(3600, false, "CET"));
+        assert_eq!(offset(&rome, time), local(7200, true, "CEST"));
+        assert_eq!(
+            offset(&rome, utc(2099, 12, 25, 0, 0, 0)),
+            local(3600, false, "CET")
+        );
+    }
+
+    #[test]
+    fn sydney_across_the_new_year() {
+        let sydney = zone("Australia/Sydney");
+        assert_eq!(
+            offset(&sydney, utc(2023, 12, 31, 23, 0, 0)),
+            local(39600, true, "AEDT")
+        );
+        assert_eq!(
+            offset(&sydney, utc(2024, 1, 1, 1, 0, 0)),
+            local(39600, true, "AEDT")
+        );
+        assert_eq!(
+            offset(&sydney, utc(2024, 4, 6, 15, 59, 59)),
+            local(39600, true, "AEDT")
+        );
+        assert_eq!(
+            offset(&sydney, utc(2024, 4, 6, 16, 0, 0)),
+            local(36000, false, "AEST")
+        );
+        assert_eq!(
+            offset(&sydney, utc(2024, 10, 5, 15, 59, 59)),
+            local(36000, false, "AEST")
+        );
+        assert_eq!(
+            offset(&sydney, utc(2024, 10, 5, 16, 0, 0)),
+            local(39600, true, "AEDT")
+        );
+
+        // The same from the rule, past the transitions listed
+        assert_eq!(
+            offset(&sydney, utc(2090, 1, 1, 0, 0, 0)),
+            local(39600, true, "AEDT")
+        );
+        assert_eq!(
+            offset(&sydney, utc(2090, 7, 1, 0, 0, 0)),
+            local(36000, false, "AEST")
+        );
+    }
+
+    #[test]
+    fn sao_paulo_without_daylight_saving() {
+        let sao_paulo = zone("America/Sao_Paulo");
+        assert_eq!(
+            offset(&sao_paulo, utc(2018, 2, 18, 1, 59, 59)),
+            local(-7200, true, "-02")
+        );
+        assert_eq!(
+            offset(&sao_paulo, utc(2018, 2, 18, 2, 0, 0)),
+            local(-10800, false, "-03")
+        );
+        assert_eq!(
+            offset(&sao_paulo, utc(2018, 11, 4, 3, 0, 0)),
+            local(-7200, true, "-02")
+        );
+
+        // Daylight saving was abolished in 2019
+        assert_eq!(
+            offset(&sao_paulo, utc(2030, 1, 15, 12, 0, 0)),
+            local(-10800, false, "-03")
+        );
+    }
+
+    #[test]
+    fn kolkata_and_utc() {
+        let kolkata = zone("Asia/Kolkata");
+        assert_eq!(
+            offset(&kolkata, utc(2024, 6, 1, 0, 0, 0)),
+            local(19800, false, "IST")
+        );
+        assert_eq!(
+            offset(&kolkata, utc(2100, 1, 1, 0, 0, 0)),
+            local(19800, false, "IST")
+        );
+
+        let utc_zone = zone("UTC");
+        assert_eq!(
+            offset(&utc_zone, utc(2024, 6, 1, 0, 0, 0)),
+            local(0, false, "UTC")
+        );
+        assert_eq!(
+            offset(&utc_zone, utc(1950, 1, 1, 0, 0, 0)),
+            local(0, false, "UTC")
+        );
+    }
+
+    #[test]
+    fn version_1_files_use_32_bit_times() {
+        let data = include_bytes!("../../tests/fixtures/zoneinfo/Europe/Rome");
+        // The first block of a newer file is a whole version 1 file
+        let mut reader = Reader { data, position: 0 };
+        let (_, counts) = reader.header().unwrap();
+        let mut version_1 = data[..reader.position + counts.block_len(4)].to_vec();
+        version_1[4] = 0;
+
+        let rome = TimeZone::parse("Europe/Rome", &version_1).unwrap();
+        assert!(rome.rule.is_none());
+        assert_eq!(
+            offset(&rome, utc(2023, 10, 29, 0, 59, 59)),
+            local(7200, true, "CEST")
+        );
+        assert_eq!(
+            offset(&rome, utc(2023, 10, 29, 1, 0, 0)),
+            local(3600, false, "CET")
+        );
+    }
+
+    #[test]
+    fn rejects_other_files() {
+        assert!(TimeZone::parse("Nowhere", b"not a zone file").is_none());
+        assert!(TimeZone::parse("Nowhere", b"TZif2").is_none());
+    }
+
+    fn date(rule: &str, year: i32) -> i64 {
+        let mut parser = RuleParser { rest: rule };
+        let date = parser.date().unwrap();
+        assert!(parser.rest.is_empty());
+        date.timestamp(year, 0).unwrap()
+    }
+
+    #[test]
+    fn rule_dates() {
+        // `Jn` never counts February 29
+        assert_eq!(date("J59", 2024), utc(2024, 2, 28, 2, 0, 0));
+        assert_eq!(date("J60", 2024), utc(2024, 3, 1, 2, 0, 0));
+        assert_eq!(date("J60", 2023), utc(2023, 3, 1, 2, 0, 0));
+        assert_eq!(date("J365", 2024), utc(2024, 12, 31, 2, 0, 0));
+
+        // `n` counts from zero, February 29 included
+        assert_eq!(date("0", 2024), utc(2024, 1, 1, 2, 0, 0));
+        assert_eq!(date("59", 2024), utc(2024, 2, 29, 2, 0, 0));
+        assert_eq!(date("59", 2023), utc(2023, 3, 1, 2, 0, 0));
+
+        // `Mm.w.d`, week 5 being the last one
+        assert_eq!(date("M3.5.0", 2024), utc(2024, 3, 31, 2, 0, 0));
+        assert_eq!(date("M10.1.0", 2024), utc(2024, 10, 6, 2, 0, 0));
+        assert_eq!(date("M2.5.4", 2024), utc(2024, 2, 29, 2, 0, 0));
+        assert_eq!(date("M2.5.4", 2023), utc(2023, 2, 23, 2, 0, 0));
+
+        // Times of day may be negative or past midnight
+        assert_eq!(date("M3.5.0/-1", 2024), utc(2024, 3, 30, 23, 0, 0));
+        assert_eq!(date("M3.5.0/25:30", 2024), utc(2024, 4, 1, 1, 30, 0));
+    }
+
+    #[test]
+    fn rules() {
+        let nepal = Rule::parse("<+0545>-5:45").unwrap();
+        assert!(nepal.dst.is_none());
+        assert_eq!(
+            nepal.std,
+            LocalType {
+                offset: 20700,
+                dst: false,
+                abbreviation: "+0545".into(),
+            }
+        );
+
+        // Without dates, the US rules apply
+        let new_york = Rule::parse("EST5EDT").unwrap();
+        assert_eq!(new_york.local_type(utc(2024, 7, 1, 0, 0, 0)).offset, -14400);
+        assert_eq!(
+            new_york.local_type(utc(2024, 3, 10, 6, 59, 59)).offset,
+            -18000
+        );
+        assert_eq!(
+            new_york.local_type(utc(2024, 3, 10, 7, 0, 0)).offset,
+            -14400
+        );
+
+        assert!(Rule::parse("CET-1CEST,M13.1.0,M10.5.0").is_none());
+        assert!(Rule::parse("CET-1CEST,M3.5.0").is_none());
+        assert!(Rule::parse("X1").is_none());
+    }
+}
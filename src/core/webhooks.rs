@@ -0,0 +1,318 @@
+// SPDX-License-Identifier: {{LICENSE}}
+
+//! Posts timer events as JSON to the user's webhook URLs.
+//!
+//! Webhooks are meant for bridges running on the local machine or network, so only
+//! plain `http://` URLs are supported and a minimal HTTP/1.1 client is used. Each URL
+//! has its own queue on a worker thread, so its events arrive in the order they
+//! happened while a slow or unreachable server doesn't hold back the others.
+
+use std::collections::HashMap;
+use std::io;
+use std::thread;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader};
+use tokio::net::TcpStream;
+use tokio::sync::mpsc;
+use url::Url;
+
+use crate::core::timer::TimerEvent;
+
+/// An event waiting to be posted.
+#[derive(Clone)]
+struct Delivery {
+    event: TimerEvent,
+    /// Unix time at which the event happened.
+    at: u64,
+    timeout: Duration,
+    retries: u32,
+}
+
+/// Handle to the worker posting events.
+///
+/// The worker isn't waited for, events still queued when the application exits are
+/// lost.
+pub struct Webhooks {
+    sender: mpsc::UnboundedSender<(Vec<String>, Delivery)>,
+}
+
+impl Webhooks {
+    pub fn new() -> Self {
+        let (sender, receiver) = mpsc::unbounded_channel();
+
+        let spawned = thread::Builder::new()
+            .name("chronos-webhooks".into())
+            .spawn(move || {
+                let runtime = match tokio::runtime::Builder::new_current_thread()
+                    .enable_all()
+                    .build()
+                {
+                    Ok(runtime) => runtime,
+                    Err(err) => {
+                        log::error!("failed to start webhook runtime: {err}");
+                        return;
+                    }
+                };
+                runtime.block_on(worker(receiver));
+            });
+        if let Err(err) = spawned {
+            log::error!("failed to start webhook thread: {err}");
+        }
+
+        Self { sender }
+    }
+
+    /// Queues `event` to be posted to each of `urls` after the events before it.
+    pub fn post(&self, urls: Vec<String>, event: TimerEvent, timeout: Duration, retries: u32) {
+        if urls.is_empty() {
+            return;
+        }
+        let at = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|elapsed| elapsed.as_secs())
+            .unwrap_or_default();
+        let _ = self.sender.send((
+            urls,
+            Delivery {
+                event,
+                at,
+                timeout,
+                retries,
+            },
+        ));
+    }
+}
+
+async fn worker(mut receiver: mpsc::UnboundedReceiver<(Vec<String>, Delivery)>) {
+    let mut queues: HashMap<String, mpsc::UnboundedSender<Delivery>> = HashMap::new();
+
+    while let Some((urls, delivery)) = receiver.recv().await {
+        // The queue of a URL no longer set ends once its last events are posted
+        queues.retain(|url, _| urls.contains(url));
+        for url in urls {
+            let queue = queues
+                .entry(url.clone())
+                .or_insert_with(|| spawn_queue(url));
+            let _ = queue.send(delivery.clone());
+        }
+    }
+}
+
+/// Starts posting the events sent to the returned queue to `url`, one at a time.
+fn spawn_queue(url: String) -> mpsc::UnboundedSender<Delivery> {
+    let (sender, mut receiver) = mpsc::unbounded_channel::<Delivery>();
+    tokio::spawn(async move {
+        while let Some(delivery) = receiver.recv().await {
+            post(&url, &delivery).await;
+        }
+    });
+    sender
+}
+
+/// Posts the event to `url`, retrying up to `retries` times with an increasing delay.
+///
+/// Each attempt is abandoned after `timeout`. Only unreachable servers, timeouts and
+/// server errors are retried, failures are only logged.
+async fn post(url: &str, delivery: &Delivery) {
+    let Delivery {
+        ref event,
+        timeout,
+        retries,
+        ..
+    } = *delivery;
+    let body = payload(delivery).to_string();
+
+    for attempt in 0..=retries {
+        match tokio::time::timeout(timeout, send(url, &body)).await {
+            Ok(Ok(status)) if (200..300).contains(&status) => {
+                log::debug!("{} webhook '{}' answered {}", event.kind, url, status);
+                return;
+            }
+            Ok(Ok(status)) if status >= 500 => {
+                log::warn!("{} webhook '{}' answered {}", event.kind, url, status);
+            }
+            Ok(Ok(status)) => {
+                // The request itself was refused, sending it again won't help
+                log::error!("{} webhook '{}' answered {}", event.kind, url, status);
+                return;
+            }
+            Ok(Err(err)) if is_permanent(&err) => {
+                log::error!("{} webhook '{}' failed: {}", event.kind, url, err);
+                return;
+            }
+            Ok(Err(err)) => {
+                log::warn!("{} webhook '{}' failed: {}", event.kind, url, err);
+            }
+            Err(_) => {
+                log::warn!(
+                    "{} webhook '{}' timed out after {}s",
+                    event.kind,
+                    url,
+                    timeout.as_secs()
+                );
+            }
+        }
+
+        if attempt < retries {
+            tokio::time::sleep(Duration::from_secs(1 << attempt.min(5))).await;
+        }
+    }
+
+    log::error!(
+        "giving up on {} webhook '{}' after {} attempts",
+        event.kind,
+        url,
+        retries + 1
+    );
+}
+
+/// Whether `err` comes from the URL itself rather than from reaching the server.
+fn is_permanent(err: &io::Error) -> bool {
+    matches!(
+        err.kind(),
+        io::ErrorKind::InvalidInput | io::ErrorKind::Unsupported
+    )
+}
+
+fn payload(delivery: &Delivery) -> serde_json::Value {
+    let event = &delivery.event;
+    serde_json::json!({
+        "event": event.kind.id(),
+        "phase": event.phase.id(),
+        "duration": event.duration,
+        "task": event.task,
+        "cycle": event.cycle,
+        "timestamp": delivery.at,
+    })
+}
+
+/// Sends a single request and returns the response's status code.
+async fn send(url: &str, body: &str) -> io::Result<u16> {
+    let url = Url::parse(url).map_err(|err| io::Error::new(io::ErrorKind::InvalidInput, err))?;
+    if url.scheme() != "http" {
+        return Err(io::Error::new(
+            io::ErrorKind::Unsupported,
+            "only http:// URLs are supported",
+        ));
+    }
+
+    let host = url
+        .host_str()
+        .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidInput, "missing host"))?;
+    let port = url.port().unwrap_or(80);
+    let path = match url.query() {
+        Some(query) => format!("{}?{}", url.path(), query),
+        None => url.path().to_owned(),
+    };
+
+    let address = host.trim_start_matches('[').trim_end_matches(']');
+    let mut stream = TcpStream::connect((address, port)).await?;
+
+    let request = format!(
+        "POST {path} HTTP/1.1\r\n\
+         Host: {host}:{port}\r\n\
+         User-Agent: Chronos/{}\r\n\
+         Content-Type: application/json\r\n\
+         Content-Length: {}\r\n\
+         Connection: close\r\n\
+         \r\n\
+         {body}",
+        env!("CARGO_PKG_VERSION"),
+        body.len(),
+    );
+    stream.write_all(request.as_bytes()).await?;
+
+    let mut status_line = String::new();
+    BufReader::new(stream).read_line(&mut status_line).await?;
+
+    status_line
+        .split_whitespace()
+        .nth(1)
+        .and_then(|status| status.parse().ok())
+        .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, "malformed HTTP response"))
+}
+
+#[cfg(test)]
+mod tests {
+    use tokio::net::TcpListener;
+
+    use super::*;
+    use crate::core::timer::{Phase, TimerEventKind};
+
+    fn event(kind: TimerEventKind) -> TimerEvent {
+        TimerEvent {
+            kind,
+            phase: Phase::Focus,
+            duration: 1500,
+            task: String::new(),
+            cycle: 0,
+        }
+    }
+
+    /// Answers every request with 200 and sends the bodies it received.
+    async fn server(listener: TcpListener, bodies: mpsc::UnboundedSender<serde_json::Value>) {
+        loop {
+            let (stream, _) = listener.accept().await.unwrap();
+            let mut reader = BufReader::new(stream);
+            let mut length = 0;
+            loop {
+                let mut line = String::new();
+                reader.read_line(&mut line).await.unwrap();
+                if let Some(value) = line.strip_prefix("Content-Length: ") {
+                    length = value.trim().parse().unwrap();
+                }
+                if line == "\r\n" {
+                    break;
+                }
+            }
+            let mut body = vec![0; length];
+            tokio::io::AsyncReadExt::read_exact(&mut reader, &mut body)
+                .await
+                .unwrap();
+            let mut stream = reader.into_inner();
+            stream
+                .write_all(b"HTTP/1.1 200 OK\r\nContent-Length: 0\r\n\r\n")
+                .await
+                .unwrap();
+            let _ = bodies.send(serde_json::from_slice(&body).unwrap());
+        }
+    }
+
+    #[tokio::test]
+    async fn a_stuck_server_does_not_hold_back_the_others() {
+        // Accepts connections but never answers
+        let stuck = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let stuck_url = format!("http://{}/", stuck.local_addr().unwrap());
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("http://{}/hook", listener.local_addr().unwrap());
+        let (sender, mut bodies) = mpsc::unbounded_channel();
+        tokio::spawn(server(listener, sender));
+
+        let webhooks = Webhooks::new();
+        let before = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap()
+            .as_secs();
+        for kind in [TimerEventKind::FocusStart, TimerEventKind::FocusEnd] {
+            webhooks.post(
+                vec![stuck_url.clone(), url.clone()],
+                event(kind),
+                Duration::from_secs(60),
+                3,
+            );
+        }
+
+        let mut received = Vec::new();
+        for _ in 0..2 {
+            let body = tokio::time::timeout(Duration::from_secs(5), bodies.recv())
+                .await
+                .expect("held back by the stuck server")
+                .unwrap();
+            assert!(body["timestamp"].as_u64().unwrap() >= before);
+            received.push(body["event"].as_str().unwrap().to_string());
+        }
+        assert_eq!(received, ["focus-start", "focus-end"]);
+        drop(stuck);
+    }
+}
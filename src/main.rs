@@ -2,6 +2,8 @@
 
 use cosmic::iced::Size;
 
+use crate::core::cli::{self, Command};
+
 mod app;
 mod config;
 mod core;
@@ -10,6 +12,26 @@ mod pages;
 
 fn main() -> cosmic::iced::Result {
     env_logger::init();
+
+    let flags = match cli::parse(std::env::args().skip(1)) {
+        Ok(Command::Run(flags)) => flags,
+        Ok(Command::Status(options)) => {
+            if let Err(err) = crate::core::status::run(options) {
+                eprintln!("chronos: {err}");
+                std::process::exit(1);
+            }
+            return Ok(());
+        }
+        Ok(Command::Help) => {
+            println!("{}", cli::usage());
+            return Ok(());
+        }
+        Err(err) => {
+            eprintln!("chronos: {err}\n\n{}", cli::usage());
+            std::process::exit(2);
+        }
+    };
+
     // Get the system's preferred languages.
     let requested_languages = i18n_embed::DesktopLanguageRequester::requested_languages();
 
@@ -19,6 +41,7 @@ fn main() -> cosmic::iced::Result {
     // Settings for configuring the application window and iced runtime.
     let settings = cosmic::app::Settings::default().size(Size::new(500., 800.));
 
-    // Starts the application's event loop with `()` as the application's flags.
-    cosmic::app::run::<app::AppModel>(settings, ())
+    // Starts the application's event loop, or hands the flags over to the instance
+    // which is already running in this session.
+    cosmic::app::run_single_instance::<app::AppModel>(settings, flags)
 }
@@ -0,0 +1,420 @@
+use chrono::{DateTime, Local, TimeDelta};
+use cosmic::iced::Alignment;
+use cosmic::{
+    iced::{Length, Padding},
+    widget::{self, icon},
+    Element, Task,
+};
+use notify_rust::Notification;
+
+use crate::core::alarms::{self, Alarm, Snoozed};
+use crate::{app::Message, config::Config, fl};
+
+#[derive(Debug, Clone)]
+pub enum AlarmsMessage {
+    /// The next alarm time was reached.
+    Due,
+    LabelChanged(String),
+    SoundChanged(String),
+    HourChanged(u32),
+    MinuteChanged(u32),
+    DayToggled(usize),
+    Add,
+    EnabledToggled(usize, bool),
+    Remove(usize),
+    SnoozeMinutesChanged(u32),
+    Snooze(Alarm),
+    Dismiss(Alarm),
+    /// The alarm's notification was closed, snoozing it when asked to.
+    NotificationClosed(Option<Alarm>),
+}
+
+pub struct Alarms {
+    alarms: Vec<Alarm>,
+    /// Alarms that rang and are waiting to be snoozed or dismissed.
+    ringing: Vec<Alarm>,
+    snoozed: Vec<Snoozed>,
+    /// Alarms due up to this time already rang.
+    checked_at: DateTime<Local>,
+    snooze_minutes: u32,
+    snooze_minutes_str: String,
+    new_alarm: Alarm,
+    hour_str: String,
+    minute_str: String,
+}
+
+impl Default for Alarms {
+    fn default() -> Self {
+        let config = Config::load().1;
+        let new_alarm = Alarm {
+            hour: 7,
+            enabled: true,
+            ..Default::default()
+        };
+        Self {
+            alarms: config.alarms,
+            ringing: Vec::new(),
+            snoozed: alarms::load_snoozed(),
+            checked_at: Local::now(),
+            snooze_minutes: config.alarm_snooze_minutes,
+            snooze_minutes_str: format!("{} {}", config.alarm_snooze_minutes, fl!("minutes")),
+            hour_str: format!("{:02}", new_alarm.hour),
+            minute_str: format!("{:02}", new_alarm.minute),
+            new_alarm,
+        }
+    }
+}
+
+impl Alarms {
+    pub fn view<'a>(&'a self) -> Element<'a, AlarmsMessage> {
+        let mut col = widget::column()
+            .push(widget::text::title1(fl!("alarms")))
+            .push(widget::Space::with_height(10));
+        for alarm in &self.ringing {
+            col = col
+                .push(self.ringing_view(alarm))
+                .push(widget::Space::with_height(10));
+        }
+        let col = col
+            .push(self.new_view())
+            .push(self.list_view())
+            .align_x(Alignment::Center);
+
+        widget::container(col)
+            .width(Length::Fill)
+            .height(Length::Fill)
+            .align_x(Alignment::Center)
+            .align_y(Alignment::Center)
+            .into()
+    }
+
+    fn ringing_view<'a>(&'a self, alarm: &'a Alarm) -> Element<'a, AlarmsMessage> {
+        widget::container(
+            widget::row()
+                .push(
+                    widget::column()
+                        .push(widget::text::title4(format_time(alarm)))
+                        .push(widget::text::text(alarm_title(alarm)))
+                        .width(Length::Fill),
+                )
+                .push(
+                    widget::button::standard(fl!("alarm-snooze"))
+                        .on_press(AlarmsMessage::Snooze(alarm.clone())),
+                )
+                .push(
+                    widget::button::suggested(fl!("alarm-dismiss"))
+                        .on_press(AlarmsMessage::Dismiss(alarm.clone())),
+                )
+                .spacing(10)
+                .align_y(Alignment::Center)
+                .padding(Padding::from(10)),
+        )
+        .class(cosmic::theme::Container::Card)
+        .width(Length::Fixed(350.))
+        .into()
+    }
+
+    fn new_view<'a>(&'a self) -> Element<'a, AlarmsMessage> {
+        let mut days = widget::row().spacing(5);
+        for (index, name) in alarms::weekday_names().into_iter().enumerate() {
+            let button = if self.new_alarm.days[index] {
+                widget::button::suggested(name)
+            } else {
+                widget::button::standard(name)
+            };
+            days = days.push(button.on_press(AlarmsMessage::DayToggled(index)));
+        }
+
+        widget::container(
+            widget::column()
+                .push(
+                    widget::text_input(fl!("alarm-label"), &self.new_alarm.label)
+                        .on_input(AlarmsMessage::LabelChanged),
+                )
+                .push(widget::Space::with_height(5))
+                .push(
+                    widget::text_input(fl!("sound-default"), &self.new_alarm.sound)
+                        .on_input(AlarmsMessage::SoundChanged),
+                )
+                .push(widget::Space::with_height(5))
+                .push(
+                    widget::row()
+                        .push(widget::spin_button(
+                            self.hour_str.clone(),
+                            self.new_alarm.hour,
+                            1,
+                            0,
+                            23,
+                            AlarmsMessage::HourChanged,
+                        ))
+                        .push(widget::text::title4(":"))
+                        .push(widget::spin_button(
+                            self.minute_str.clone(),
+                            self.new_alarm.minute,
+                            1,
+                            0,
+                            59,
+                            AlarmsMessage::MinuteChanged,
+                        ))
+                        .push(widget::horizontal_space())
+                        .push(
+                            widget::button::standard(fl!("add-alarm")).on_press(AlarmsMessage::Add),
+                        )
+                        .spacing(5)
+                        .align_y(Alignment::Center),
+                )
+                .push(widget::Space::with_height(5))
+                .push(days)
+                .push(widget::Space::with_height(5))
+                .push(
+                    widget::row()
+                        .push(widget::text::text(fl!("alarm-snooze-minutes")).width(Length::Fill))
+                        .push(widget::spin_button(
+                            self.snooze_minutes_str.clone(),
+                            self.snooze_minutes,
+                            1,
+                            1,
+                            60,
+                            AlarmsMessage::SnoozeMinutesChanged,
+                        ))
+                        .align_y(Alignment::Center),
+                )
+                .padding(Padding::from(10)),
+        )
+        .class(cosmic::theme::Container::Card)
+        .width(Length::Fixed(350.))
+        .into()
+    }
+
+    fn list_view<'a>(&'a self) -> Element<'a, AlarmsMessage> {
+        let mut inner_col = widget::column();
+        if self.alarms.is_empty() {
+            inner_col = inner_col.push(widget::text::text(fl!("no-elements")));
+        }
+        for (index, alarm) in self.alarms.iter().enumerate() {
+            inner_col =
+                inner_col.push(
+                    widget::row()
+                        .push(
+                            widget::column()
+                                .push(widget::text::title4(format_time(alarm)))
+                                .push(widget::text::caption(format!(
+                                    "{} · {}",
+                                    alarm_title(alarm),
+                                    format_days(alarm)
+                                )))
+                                .width(Length::Fill),
+                        )
+                        .push(widget::toggler(alarm.enabled).on_toggle(move |enabled| {
+                            AlarmsMessage::EnabledToggled(index, enabled)
+                        }))
+                        .push(
+                            widget::button::icon(icon::from_name("user-trash-symbolic"))
+                                .on_press(AlarmsMessage::Remove(index)),
+                        )
+                        .spacing(5)
+                        .align_y(Alignment::Center),
+                );
+            inner_col = inner_col.push(widget::Space::with_height(5));
+            inner_col = inner_col.push(widget::divider::horizontal::default());
+            inner_col = inner_col.push(widget::Space::with_height(5));
+        }
+
+        widget::column()
+            .push(widget::Space::with_height(20))
+            .push(
+                widget::container(widget::column().push(inner_col).padding(Padding::from(10)))
+                    .class(cosmic::theme::Container::Card)
+                    .width(Length::Fixed(350.)),
+            )
+            .into()
+    }
+
+    pub fn update(&mut self, message: AlarmsMessage) -> Task<Message> {
+        let mut commands = vec![];
+        match message {
+            AlarmsMessage::Due => {
+                let now = Local::now();
+                let mut due: Vec<Alarm> = Vec::new();
+                let mut changed = false;
+                for alarm in &mut self.alarms {
+                    if alarm
+                        .next_after(self.checked_at)
+                        .is_some_and(|at| at <= now)
+                    {
+                        due.push(alarm.clone());
+                        if !alarm.repeats() {
+                            alarm.enabled = false;
+                            changed = true;
+                        }
+                    }
+                }
+                let snoozed_before = self.snoozed.len();
+                self.snoozed.retain(|snoozed| {
+                    if snoozed.at <= now.timestamp() {
+                        due.push(snoozed.alarm.clone());
+                        false
+                    } else {
+                        true
+                    }
+                });
+                self.checked_at = now;
+                if changed {
+                    self.save();
+                }
+                if self.snoozed.len() != snoozed_before {
+                    alarms::save_snoozed(&self.snoozed);
+                }
+
+                for alarm in due {
+                    log::info!("alarm '{}' ringing", alarm.label);
+                    let sound = alarm.sound.clone();
+                    commands.push(Task::perform(async {}, move |_| {
+                        Message::AlarmRang(sound.clone())
+                    }));
+                    commands.push(Task::perform(notify(alarm.clone()), |snooze| {
+                        Message::Alarms(AlarmsMessage::NotificationClosed(snooze))
+                    }));
+                    self.ringing.retain(|ringing| *ringing != alarm);
+                    self.ringing.push(alarm);
+                }
+            }
+            AlarmsMessage::LabelChanged(label) => {
+                self.new_alarm.label = label;
+            }
+            AlarmsMessage::SoundChanged(sound) => {
+                self.new_alarm.sound = sound;
+            }
+            AlarmsMessage::HourChanged(hour) => {
+                self.new_alarm.hour = hour;
+                self.hour_str = format!("{hour:02}");
+            }
+            AlarmsMessage::MinuteChanged(minute) => {
+                self.new_alarm.minute = minute;
+                self.minute_str = format!("{minute:02}");
+            }
+            AlarmsMessage::DayToggled(index) => {
+                if let Some(day) = self.new_alarm.days.get_mut(index) {
+                    *day = !*day;
+                }
+            }
+            AlarmsMessage::Add => {
+                // Ring what is already due, so the new alarm only counts from now on
+                commands.push(self.update(AlarmsMessage::Due));
+                let mut alarm = self.new_alarm.clone();
+                alarm.label = alarm.label.trim().to_string();
+                alarm.sound = alarm.sound.trim().to_string();
+                self.alarms.push(alarm);
+                self.new_alarm.label.clear();
+                self.save();
+            }
+            AlarmsMessage::EnabledToggled(index, enabled) => {
+                // An alarm enabled past its time waits for the next one
+                commands.push(self.update(AlarmsMessage::Due));
+                if let Some(alarm) = self.alarms.get_mut(index) {
+                    alarm.enabled = enabled;
+                    self.save();
+                }
+            }
+            AlarmsMessage::Remove(index) => {
+                if index < self.alarms.len() {
+                    self.alarms.remove(index);
+                    self.save();
+                }
+            }
+            AlarmsMessage::SnoozeMinutesChanged(minutes) => {
+                self.snooze_minutes = minutes;
+                self.snooze_minutes_str = format!("{} {}", minutes, fl!("minutes"));
+                let mut config = Config::load();
+                let _ = config
+                    .1
+                    .set_alarm_snooze_minutes(&config.0.unwrap(), minutes);
+            }
+            AlarmsMessage::Snooze(alarm) => {
+                log::info!("snoozing alarm '{}'", alarm.label);
+                self.ringing.retain(|ringing| *ringing != alarm);
+                let at = Local::now() + TimeDelta::minutes(self.snooze_minutes as i64);
+                self.snoozed.push(Snoozed {
+                    alarm,
+                    at: at.timestamp(),
+                });
+                alarms::save_snoozed(&self.snoozed);
+            }
+            AlarmsMessage::Dismiss(alarm) => {
+                self.ringing.retain(|ringing| *ringing != alarm);
+            }
+            AlarmsMessage::NotificationClosed(snooze) => {
+                if let Some(alarm) = snooze {
+                    return self.update(AlarmsMessage::Snooze(alarm));
+                }
+            }
+        }
+        Task::batch(commands)
+    }
+
+    /// Unix time of the next alarm or snoozed alarm to ring.
+    pub fn next_due(&self) -> Option<i64> {
+        let alarms = self
+            .alarms
+            .iter()
+            .filter_map(|alarm| alarm.next_after(self.checked_at))
+            .map(|at| at.timestamp());
+        let snoozed = self.snoozed.iter().map(|snoozed| snoozed.at);
+        alarms.chain(snoozed).min()
+    }
+
+    fn save(&self) {
+        let mut config = Config::load();
+        let _ = config.1.set_alarms(&config.0.unwrap(), self.alarms.clone());
+    }
+}
+
+/// Shows the alarm, returning it when the user asks to snooze it.
+async fn notify(alarm: Alarm) -> Option<Alarm> {
+    let handle = Notification::new()
+        .summary(&alarm_title(&alarm))
+        .body(&format_time(&alarm))
+        .action("snooze", &fl!("alarm-snooze"))
+        .action("dismiss", &fl!("alarm-dismiss"))
+        .appname("Chronos")
+        .show()
+        .map_err(|err| log::warn!("failed to show notification: {err}"))
+        .ok()?;
+
+    tokio::task::spawn_blocking(move || {
+        let mut snooze = false;
+        handle.wait_for_action(|action| snooze = action == "snooze");
+        snooze.then_some(alarm)
+    })
+    .await
+    .ok()
+    .flatten()
+}
+
+fn alarm_title(alarm: &Alarm) -> String {
+    if alarm.label.is_empty() {
+        fl!("alarm")
+    } else {
+        alarm.label.clone()
+    }
+}
+
+fn format_time(alarm: &Alarm) -> String {
+    format!("{:02}:{:02}", alarm.hour, alarm.minute)
+}
+
+fn format_days(alarm: &Alarm) -> String {
+    if !alarm.repeats() {
+        return fl!("alarm-once");
+    }
+    if alarm.days.iter().all(|&day| day) {
+        return fl!("alarm-every-day");
+    }
+    alarms::weekday_names()
+        .into_iter()
+        .zip(alarm.days)
+        .filter(|(_, set)| *set)
+        .map(|(name, _)| name)
+        .collect::<Vec<_>>()
+        .join(" ")
+}
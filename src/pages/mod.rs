@@ -1,2 +1,7 @@
+pub mod alarms;
 pub mod pomodoro;
-pub mod settings;
\ No newline at end of file
+pub mod settings;
+pub mod statistics;
+pub mod stopwatch;
+pub mod timers;
+pub mod world_clock;
\ No newline at end of file
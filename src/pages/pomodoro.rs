@@ -1,3 +1,4 @@
+use chrono::{DateTime, Local, NaiveDate};
 use cosmic::iced::Alignment;
 use cosmic::{
     iced::{self, Length, Padding},
@@ -6,11 +7,32 @@ use cosmic::{
 };
 use notify_rust::Notification;
 
+use crate::core::history::{
+    self, DaySummary, Entry, EntryKind, GoalUnit, Interruption, InterruptionKind,
+};
+use crate::core::status::Status;
+use crate::core::timer::{format_duration, Phase, RunState, TimerEvent, TimerEventKind, TimerMode};
 use crate::{app::Message, config::Config, fl};
 
-enum CompletedItem {
-    Pomodoro(u32),
-    Pause(u32),
+const RING_SIZE: f32 = 240.;
+const RING_WIDTH: f32 = 12.;
+const CYCLE_DOT: f32 = 10.;
+/// Shortest flowtime break in seconds, however briefly the focus lasted.
+const FLOWTIME_MIN_BREAK: u32 = 60;
+/// Days of history shown at first, and added by each "show more".
+const HISTORY_DAYS: usize = 7;
+
+/// Theme color identifying `phase`, as an SVG color.
+fn phase_color(phase: Phase) -> String {
+    let theme = cosmic::theme::active();
+    let cosmic = theme.cosmic();
+    let color = match phase {
+        Phase::Focus => cosmic.accent_color(),
+        Phase::ShortBreak => cosmic.success_color(),
+        Phase::LongBreak => cosmic.palette.accent_purple,
+    }
+    .into_format::<u8, u8>();
+    format!("#{:02x}{:02x}{:02x}", color.red, color.green, color.blue)
 }
 
 #[derive(Debug, Clone)]
@@ -20,10 +42,141 @@ pub enum PomodoroMessage {
     StartPomodoro,
     PausePomodoro,
     ResetPomodoro,
+    TaskChanged(String),
+    /// The user left the computer.
+    Away,
+    /// The user is back after being away.
+    Back,
+    AwayChoice(AwayChoice),
+    AwayNotificationClosed(Option<AwayChoice>),
+    /// Ends the current phase early and moves on to the next one.
+    Skip,
+    PostponeBreak,
+    /// The break postponing was changed in the settings.
+    UpdateBreakPostpone,
+    ModeChanged(TimerMode),
+    /// The flowtime break ratio was changed in the settings.
+    UpdateFlowtimeBreakRatio,
+    /// The daily goal was changed in the settings.
+    UpdateGoal,
+    /// Time for the end-of-day summary.
+    DailySummary,
+    /// The end-of-day summary was turned on or its time changed.
+    UpdateDailySummary,
+    /// Opens the form logging an interruption of the focus block.
+    Interrupt,
+    InterruptionKindChanged(InterruptionKind),
+    InterruptionNoteChanged(String),
+    LogInterruption,
+    CancelInterruption,
+    /// Opens the history entry with these start and end times for editing.
+    EditEntry(u64, u64),
+    /// Opens an empty entry, for a block done away from the computer.
+    AddEntry,
+    EntryKindChanged(EntryKind),
+    EntryDateChanged(NaiveDate),
+    EntryMinutesChanged(u32),
+    EntryEndHourChanged(u32),
+    EntryEndMinuteChanged(u32),
+    EntryTaskChanged(String),
+    EntryNoteChanged(String),
+    EntryRatingChanged(u8),
+    SaveEntry,
+    DeleteEntry,
+    CancelEntry,
+    HistorySearchChanged(String),
+    HistoryShowBreaksToggled(bool),
+    HistoryShowMore,
+}
+
+/// History entry being written or corrected.
+struct EntryForm {
+    /// Start and end times of the entry being edited, none for one being added. Its
+    /// position in the history changes as entries are recorded and pruned.
+    editing: Option<(u64, u64)>,
+    /// Only the note and rating are asked for right after a focus block.
+    reflecting: bool,
+    kind: EntryKind,
+    minutes: u32,
+    minutes_str: String,
+    /// Local day, hour and minute it ended at.
+    date: NaiveDate,
+    end: (u32, u32),
+    end_hour_str: String,
+    end_minute_str: String,
+    task: String,
+    note: String,
+    rating: Option<u8>,
+}
+
+impl EntryForm {
+    fn new(editing: bool, entry: &Entry) -> Self {
+        let minutes = entry.duration / 60;
+        let end = history::local_time(entry.ended_at);
+        Self {
+            editing: editing.then_some((entry.started_at, entry.ended_at)),
+            reflecting: false,
+            kind: entry.kind,
+            minutes,
+            minutes_str: format!("{} {}", minutes, fl!("minutes")),
+            date: entry.date(),
+            end,
+            end_hour_str: format!("{:02}", end.0),
+            end_minute_str: format!("{:02}", end.1),
+            task: entry.task.clone(),
+            note: entry.note.clone(),
+            rating: entry.rating,
+        }
+    }
+
+    /// The entry with the changes of the form applied to `existing`, or a new one.
+    fn entry(&self, existing: Option<&Entry>, now: u64) -> Entry {
+        let mut entry = existing.cloned().unwrap_or_default();
+        entry.kind = self.kind;
+        entry.task = self.task.trim().to_string();
+        entry.note = self.note.trim().to_string();
+        entry.rating = self.rating;
+
+        // Keep the exact times unless they were changed
+        let unchanged = existing.is_some_and(|existing| {
+            existing.duration / 60 == self.minutes
+                && existing.date() == self.date
+                && history::local_time(existing.ended_at) == self.end
+        });
+        if !unchanged {
+            // Nothing can have ended later than now
+            entry.ended_at = history::local_timestamp(self.date, self.end)
+                .unwrap_or(now)
+                .min(now);
+            entry.duration = self.minutes * 60;
+            entry.started_at = entry.ended_at.saturating_sub(entry.duration as u64);
+        }
+        entry
+    }
+}
+
+/// What to do with the time spent away from a paused focus block.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum AwayChoice {
+    /// Continue the block where it was paused.
+    Resume,
+    /// The block was interrupted, start it over.
+    Discard,
+    /// Count the time away as focus time and continue.
+    Count,
 }
 
 pub struct Pomodoro {
-    in_action: bool,
+    state: RunState,
+    mode: TimerMode,
+    phase: Phase,
+    task: String,
+    /// Unix time at which the user left, set while a focus block is paused for it.
+    ///
+    /// Wall-clock time, since a monotonic clock stops while the system is suspended.
+    away_since: Option<u64>,
+    /// Seconds spent away, until the user decides what to do with them.
+    returned_after: Option<u32>,
     slider_max_value: f32,
     slider_value: f32,
     timer_duration: f32,
@@ -31,17 +184,54 @@ pub struct Pomodoro {
     long_pause_duration: f32,
     pomodoro_completed: u32,
     pomodoro_before_long_pause: u32,
-    history: Vec<CompletedItem>,
+    history: Vec<Entry>,
+    /// Unix time at which the current phase was first started.
+    phase_started_at: Option<u64>,
+    daily_goal: u32,
+    daily_goal_unit: GoalUnit,
+    /// When the end-of-day summary was last shown or set up, the next one being
+    /// the first after it.
+    summary_checked_at: DateTime<Local>,
+    /// Interruptions of the current focus block.
+    interruptions: Vec<Interruption>,
+    /// Interruption being described before it's logged.
+    interruption_form: Option<Interruption>,
+    entry_form: Option<EntryForm>,
+    history_search: String,
+    history_show_breaks: bool,
+    /// Days with matching entries shown in the history.
+    history_days: usize,
     notifications_active: bool,
+    /// The break to take once the focus time it was postponed for runs out.
+    postponed_break: Option<Phase>,
+    /// Start of the last focus block recorded, which a postponed break extends.
+    last_focus: Option<u64>,
+    postpones_used: u32,
+    break_postpone_minutes: u32,
+    break_postpone_limit: u32,
+    /// Seconds spent in the current flowtime focus block.
+    flow_seconds: u32,
+    /// Length of the break earned by the last flowtime focus block.
+    flow_break: u32,
+    flowtime_break_ratio: u32,
 }
 
 impl Default for Pomodoro {
     // Initialize default
     fn default() -> Self {
         let config = Config::load().1;
-        let seconds = config.timer_duration * 60;
+        // Flowtime focus blocks count up from zero
+        let seconds = match config.timer_mode {
+            TimerMode::Pomodoro => config.timer_duration * 60,
+            TimerMode::Flowtime => 0,
+        };
         Self {
-            in_action: false,
+            state: RunState::Stopped,
+            mode: config.timer_mode,
+            phase: Phase::Focus,
+            task: String::new(),
+            away_since: None,
+            returned_after: None,
             slider_value: seconds as f32,
             slider_max_value: seconds as f32,
             timer_duration: config.timer_duration as f32,
@@ -49,8 +239,26 @@ impl Default for Pomodoro {
             long_pause_duration: config.long_pause_duration as f32,
             pomodoro_completed: 0,
             pomodoro_before_long_pause: config.pomodoro_before_long_pause,
-            history: Vec::new(),
+            history: history::load(),
+            phase_started_at: None,
+            daily_goal: config.daily_goal,
+            daily_goal_unit: config.daily_goal_unit,
+            summary_checked_at: Local::now(),
+            interruptions: Vec::new(),
+            interruption_form: None,
+            entry_form: None,
+            history_search: String::new(),
+            history_show_breaks: config.history_show_breaks,
+            history_days: HISTORY_DAYS,
             notifications_active: config.notifications_active,
+            postponed_break: None,
+            last_focus: None,
+            postpones_used: 0,
+            break_postpone_minutes: config.break_postpone_minutes,
+            break_postpone_limit: config.break_postpone_limit,
+            flow_seconds: 0,
+            flow_break: 0,
+            flowtime_break_ratio: config.flowtime_break_ratio,
         }
     }
 }
@@ -59,24 +267,47 @@ impl Pomodoro {
     pub fn view<'a>(&'a self) -> Element<'a, PomodoroMessage> {
         let col = widget::column()
             .push(
-                widget::text::title1(if self.in_action {
-                    fl!("pomodoro")
-                } else {
-                    fl!("pause")
-                })
-                .width(Length::Fill)
-                .align_x(iced::alignment::Horizontal::Center),
-            )
-            .push(
-                widget::text::title1(self.format_slider_value())
+                widget::text::title1(self.phase.label())
                     .width(Length::Fill)
                     .align_x(iced::alignment::Horizontal::Center),
             )
+            .push(
+                widget::row()
+                    .push(icon::from_name(self.state.icon_name()).size(16).icon())
+                    .push(widget::text::body(self.state.label()))
+                    .spacing(5)
+                    .align_y(Alignment::Center),
+            )
+            .push(widget::Space::with_height(10))
+            .push(self.mode_view())
+            .push(widget::Space::with_height(10))
+            .push(iced::widget::stack![
+                widget::svg(widget::svg::Handle::from_memory(
+                    self.ring_svg().into_bytes()
+                ))
+                .width(Length::Fixed(RING_SIZE))
+                .height(Length::Fixed(RING_SIZE)),
+                widget::container(widget::text::title1(self.format_slider_value()))
+                    .width(Length::Fixed(RING_SIZE))
+                    .height(Length::Fixed(RING_SIZE))
+                    .align_x(Alignment::Center)
+                    .align_y(Alignment::Center),
+            ])
+            .push(widget::Space::with_height(10))
+            // Flowtime has no cycle leading to a long break
+            .push_maybe((self.mode == TimerMode::Pomodoro).then(|| {
+                widget::svg(widget::svg::Handle::from_memory(
+                    self.cycle_svg().into_bytes(),
+                ))
+                .width(Length::Shrink)
+                .height(Length::Fixed(CYCLE_DOT))
+            }))
+            .push_maybe((self.daily_goal > 0).then(|| self.goal_view()))
             .push(widget::Space::with_height(10))
             .push(
-                widget::progress_bar(0.0..=self.slider_max_value, self.slider_value)
-                    .width(Length::Fixed(250.0))
-                    .height(Length::Fixed(4.0)),
+                widget::text_input(fl!("task-placeholder"), &self.task)
+                    .on_input(PomodoroMessage::TaskChanged)
+                    .width(Length::Fixed(250.0)),
             )
             .push(widget::Space::with_height(10))
             .push(
@@ -86,7 +317,7 @@ impl Pomodoro {
                             .extra_large()
                             .on_press(PomodoroMessage::StartPomodoro),
                     )
-                    .push(if self.in_action {
+                    .push(if self.is_running() {
                         widget::button::icon(icon::from_name("media-playback-pause-symbolic"))
                             .extra_large()
                             .on_press(PomodoroMessage::PausePomodoro)
@@ -94,11 +325,114 @@ impl Pomodoro {
                         widget::button::icon(icon::from_name("media-playback-stop-symbolic"))
                             .extra_large()
                             .on_press(PomodoroMessage::ResetPomodoro)
-                    }),
+                    })
+                    .push_maybe(self.can_interrupt().then(|| {
+                        widget::button::icon(icon::from_name("dialog-warning-symbolic"))
+                            .extra_large()
+                            .tooltip(fl!("interruption-log"))
+                            .on_press(PomodoroMessage::Interrupt)
+                    }))
+                    .push_maybe((self.is_flowing() && self.state != RunState::Stopped).then(
+                        || {
+                            widget::button::icon(icon::from_name("media-skip-forward-symbolic"))
+                                .extra_large()
+                                .tooltip(fl!("flowtime-take-break"))
+                                .on_press(PomodoroMessage::Skip)
+                        },
+                    )),
+            )
+            .push_maybe(self.returned_after.map(|seconds| self.away_view(seconds)))
+            .push_maybe(
+                self.interruption_form
+                    .as_ref()
+                    .map(|interruption| self.interruption_view(interruption)),
+            )
+            .push_maybe(
+                self.entry_form
+                    .as_ref()
+                    .map(|form| self.entry_form_view(form)),
             )
             .push(self.history_view())
             .align_x(Alignment::Center);
 
+        widget::scrollable(
+            widget::container(col)
+                .width(Length::Fill)
+                .height(Length::Shrink)
+                .align_x(Alignment::Center),
+        )
+        .into()
+    }
+
+    /// The timer alone, for the compact window.
+    pub fn compact_view<'a>(&'a self) -> Element<'a, PomodoroMessage> {
+        widget::column()
+            .push(widget::text::title4(self.phase.label()))
+            .push(widget::text::title1(self.format_slider_value()))
+            .push(widget::Space::with_height(5))
+            .push(
+                widget::progress_bar(0.0..=1.0, self.progress())
+                    .width(Length::Fixed(180.0))
+                    .height(Length::Fixed(4.0)),
+            )
+            .push(widget::Space::with_height(5))
+            .push(if self.is_running() {
+                widget::button::icon(icon::from_name("media-playback-pause-symbolic"))
+                    .on_press(PomodoroMessage::PausePomodoro)
+            } else {
+                widget::button::icon(icon::from_name("media-playback-start-symbolic"))
+                    .on_press(PomodoroMessage::StartPomodoro)
+            })
+            .align_x(Alignment::Center)
+            .into()
+    }
+
+    /// Contents of the full screen window shown during breaks.
+    pub fn break_view<'a>(&'a self) -> Element<'a, PomodoroMessage> {
+        const SUGGESTION_SECONDS: u32 = 30;
+        let suggestions = [
+            fl!("break-suggestion-stretch"),
+            fl!("break-suggestion-water"),
+            fl!("break-suggestion-eyes"),
+            fl!("break-suggestion-walk"),
+            fl!("break-suggestion-breathe"),
+            fl!("break-suggestion-window"),
+        ];
+        let elapsed = (self.slider_max_value - self.slider_value) as u32;
+        let suggestion =
+            suggestions[(elapsed / SUGGESTION_SECONDS) as usize % suggestions.len()].clone();
+        let postpones_left = self
+            .break_postpone_limit
+            .saturating_sub(self.postpones_used);
+
+        let col = widget::column()
+            .push(widget::text::title1(fl!("break-time")))
+            .push(widget::Space::with_height(20))
+            .push(widget::text(self.format_slider_value()).size(96))
+            .push(widget::Space::with_height(20))
+            .push(widget::text::title3(suggestion))
+            .push(widget::Space::with_height(40))
+            .push(
+                widget::row()
+                    .push(
+                        widget::button::standard(fl!(
+                            "break-postpone",
+                            minutes = self.break_postpone_minutes,
+                            left = postpones_left
+                        ))
+                        .on_press_maybe(
+                            self.can_postpone_break()
+                                .then_some(PomodoroMessage::PostponeBreak),
+                        ),
+                    )
+                    .push(
+                        widget::button::suggested(fl!("break-skip"))
+                            .on_press(PomodoroMessage::Skip),
+                    )
+                    .spacing(20),
+            )
+            .align_x(Alignment::Center);
+
         widget::container(col)
             .width(Length::Fill)
             .height(Length::Fill)
@@ -107,63 +441,378 @@ impl Pomodoro {
             .into()
     }
 
-    pub fn history_view<'a>(&'a self) -> Element<'a, PomodoroMessage> {
-        let mut inner_col = widget::column();
-        if self.history.len() > 0 {
-            for item in &self.history {
-                match item {
-                    CompletedItem::Pomodoro(seconds) => {
-                        inner_col = inner_col.push(
+    /// Switches between fixed pomodoros and flowtime while the timer is stopped.
+    fn mode_view<'a>(&'a self) -> Element<'a, PomodoroMessage> {
+        let mut row = widget::row().spacing(5);
+        for mode in [TimerMode::Pomodoro, TimerMode::Flowtime] {
+            let button = if mode == self.mode {
+                widget::button::suggested(mode.label())
+            } else {
+                widget::button::standard(mode.label())
+            };
+            row = row.push(button.on_press_maybe(
+                (self.state == RunState::Stopped).then_some(PomodoroMessage::ModeChanged(mode)),
+            ));
+        }
+        row.into()
+    }
+
+    /// Progress toward the daily goal.
+    fn goal_view<'a>(&'a self) -> Element<'a, PomodoroMessage> {
+        let progress =
+            DaySummary::new(&self.history, history::today()).progress(self.daily_goal_unit);
+
+        widget::column()
+            .push(widget::Space::with_height(10))
+            .push(
+                widget::row()
+                    .push(widget::text::caption(fl!("daily-goal")).width(Length::Fill))
+                    .push(widget::text::caption(format!(
+                        "{} / {} {}",
+                        progress,
+                        self.daily_goal,
+                        self.daily_goal_unit.label()
+                    ))),
+            )
+            .push(widget::progress_bar(
+                0.0..=self.daily_goal as f32,
+                progress.min(self.daily_goal) as f32,
+            ))
+            .width(Length::Fixed(250.0))
+            .into()
+    }
+
+    fn away_view<'a>(&'a self, seconds: u32) -> Element<'a, PomodoroMessage> {
+        widget::column()
+            .push(widget::Space::with_height(20))
+            .push(
+                widget::container(
+                    widget::column()
+                        .push(widget::text::text(fl!(
+                            "away-returned-des",
+                            duration = format_duration(seconds)
+                        )))
+                        .push(widget::Space::with_height(10))
+                        .push(
                             widget::row()
                                 .push(
-                                    widget::column()
-                                        .push(widget::text::text(fl!("pomodoro")))
-                                        .width(Length::Fill),
+                                    widget::button::suggested(fl!("away-resume"))
+                                        .on_press(PomodoroMessage::AwayChoice(AwayChoice::Resume)),
+                                )
+                                .push(
+                                    widget::button::standard(fl!("away-count"))
+                                        .on_press(PomodoroMessage::AwayChoice(AwayChoice::Count)),
                                 )
                                 .push(
-                                    widget::column()
-                                        .push(widget::text::text(
-                                            self.format_seconds(seconds.clone()),
-                                        ))
-                                        .width(Length::Fill)
-                                        .align_x(Alignment::End),
+                                    widget::button::destructive(fl!("away-discard"))
+                                        .on_press(PomodoroMessage::AwayChoice(AwayChoice::Discard)),
+                                )
+                                .spacing(10),
+                        )
+                        .padding(Padding::from(10)),
+                )
+                .class(cosmic::theme::Container::Card)
+                .width(Length::Fixed(350.)),
+            )
+            .into()
+    }
+
+    /// Editor of a history entry, reduced to its note and rating right after a focus block.
+    fn entry_form_view<'a>(&'a self, form: &'a EntryForm) -> Element<'a, PomodoroMessage> {
+        let mut rating = widget::row().spacing(5);
+        for value in 1..=5 {
+            let name = if form.rating.is_some_and(|rating| rating >= value) {
+                "starred-symbolic"
+            } else {
+                "non-starred-symbolic"
+            };
+            rating = rating.push(
+                widget::button::icon(icon::from_name(name))
+                    .on_press(PomodoroMessage::EntryRatingChanged(value)),
+            );
+        }
+
+        let mut col = widget::column();
+        if form.reflecting {
+            col = col
+                .push(widget::text::text(fl!("reflection-title")))
+                .push(widget::Space::with_height(10));
+        } else {
+            let mut kinds = widget::row().spacing(5);
+            for &kind in EntryKind::all() {
+                let button = if kind == form.kind {
+                    widget::button::suggested(kind.label())
+                } else {
+                    widget::button::standard(kind.label())
+                };
+                kinds = kinds.push(button.on_press(PomodoroMessage::EntryKindChanged(kind)));
+            }
+
+            let today = history::today();
+            col = col
+                .push(kinds)
+                .push(widget::Space::with_height(10))
+                .push(
+                    widget::row()
+                        .push(widget::text::text(fl!("entry-date")).width(Length::Fill))
+                        .push(
+                            widget::button::icon(icon::from_name("go-previous-symbolic"))
+                                .on_press_maybe(
+                                    form.date.pred_opt().map(PomodoroMessage::EntryDateChanged),
                                 ),
-                        );
-                    }
-                    CompletedItem::Pause(seconds) => {
-                        inner_col = inner_col.push(
+                        )
+                        .push(widget::text::text(history::format_day(form.date)))
+                        .push(
+                            widget::button::icon(icon::from_name("go-next-symbolic"))
+                                .on_press_maybe(
+                                    form.date
+                                        .succ_opt()
+                                        .filter(|&date| date <= today)
+                                        .map(PomodoroMessage::EntryDateChanged),
+                                ),
+                        )
+                        .spacing(5)
+                        .align_y(Alignment::Center),
+                )
+                .push(widget::Space::with_height(5))
+                .push(
+                    widget::row()
+                        .push(widget::text::text(fl!("entry-duration")).width(Length::Fill))
+                        .push(widget::spin_button(
+                            form.minutes_str.clone(),
+                            form.minutes,
+                            1,
+                            1,
+                            1440,
+                            PomodoroMessage::EntryMinutesChanged,
+                        ))
+                        .align_y(Alignment::Center),
+                )
+                .push(widget::Space::with_height(5))
+                .push(
+                    widget::row()
+                        .push(widget::text::text(fl!("entry-end")).width(Length::Fill))
+                        .push(widget::spin_button(
+                            form.end_hour_str.clone(),
+                            form.end.0,
+                            1,
+                            0,
+                            23,
+                            PomodoroMessage::EntryEndHourChanged,
+                        ))
+                        .push(widget::text::text(":"))
+                        .push(widget::spin_button(
+                            form.end_minute_str.clone(),
+                            form.end.1,
+                            1,
+                            0,
+                            59,
+                            PomodoroMessage::EntryEndMinuteChanged,
+                        ))
+                        .spacing(5)
+                        .align_y(Alignment::Center),
+                )
+                .push(widget::Space::with_height(10))
+                .push(
+                    widget::text_input(fl!("task-placeholder"), &form.task)
+                        .on_input(PomodoroMessage::EntryTaskChanged),
+                )
+                .push(widget::Space::with_height(10));
+        }
+
+        if form.kind == EntryKind::Focus {
+            col = col
+                .push(
+                    widget::text_input(fl!("reflection-note"), &form.note)
+                        .on_input(PomodoroMessage::EntryNoteChanged),
+                )
+                .push(widget::Space::with_height(10))
+                .push(
+                    widget::row()
+                        .push(widget::text::text(fl!("reflection-rating")))
+                        .push(rating)
+                        .spacing(10)
+                        .align_y(Alignment::Center),
+                )
+                .push(widget::Space::with_height(10));
+        }
+
+        col = col.push(
+            widget::row()
+                .push(widget::button::suggested(fl!("save")).on_press(PomodoroMessage::SaveEntry))
+                .push(
+                    widget::button::standard(fl!("cancel")).on_press(PomodoroMessage::CancelEntry),
+                )
+                .push_maybe((form.editing.is_some() && !form.reflecting).then(|| {
+                    widget::button::destructive(fl!("delete"))
+                        .on_press(PomodoroMessage::DeleteEntry)
+                }))
+                .spacing(10),
+        );
+
+        widget::column()
+            .push(widget::Space::with_height(20))
+            .push(
+                widget::container(col.padding(Padding::from(10)))
+                    .class(cosmic::theme::Container::Card)
+                    .width(Length::Fixed(350.)),
+            )
+            .into()
+    }
+
+    /// Form describing an interruption, also shown when pausing a focus block.
+    fn interruption_view<'a>(
+        &'a self,
+        interruption: &'a Interruption,
+    ) -> Element<'a, PomodoroMessage> {
+        let mut kinds = widget::row().spacing(5);
+        for &kind in InterruptionKind::all() {
+            let button = if kind == interruption.kind {
+                widget::button::suggested(kind.label())
+            } else {
+                widget::button::standard(kind.label())
+            };
+            kinds = kinds.push(button.on_press(PomodoroMessage::InterruptionKindChanged(kind)));
+        }
+
+        widget::column()
+            .push(widget::Space::with_height(20))
+            .push(
+                widget::container(
+                    widget::column()
+                        .push(widget::text::text(fl!("interruption-reason")))
+                        .push(widget::Space::with_height(10))
+                        .push(kinds)
+                        .push(widget::Space::with_height(10))
+                        .push(
+                            widget::text_input(fl!("interruption-note"), &interruption.note)
+                                .on_input(PomodoroMessage::InterruptionNoteChanged),
+                        )
+                        .push(widget::Space::with_height(10))
+                        .push(
                             widget::row()
                                 .push(
-                                    widget::column()
-                                        .push(widget::text::text(fl!("pause")))
-                                        .width(Length::Fill),
+                                    widget::button::suggested(fl!("interruption-log"))
+                                        .on_press(PomodoroMessage::LogInterruption),
                                 )
                                 .push(
-                                    widget::column()
-                                        .push(widget::text::text(
-                                            self.format_seconds(seconds.clone()),
-                                        ))
-                                        .width(Length::Fill)
-                                        .align_x(Alignment::End),
-                                ),
-                        );
-                    }
+                                    widget::button::standard(fl!("cancel"))
+                                        .on_press(PomodoroMessage::CancelInterruption),
+                                )
+                                .spacing(10),
+                        )
+                        .padding(Padding::from(10)),
+                )
+                .class(cosmic::theme::Container::Card)
+                .width(Length::Fixed(350.)),
+            )
+            .into()
+    }
+
+    /// Every day of the history, most recent first, with its totals.
+    pub fn history_view<'a>(&'a self) -> Element<'a, PomodoroMessage> {
+        let search = self.history_search.trim();
+        let shown = |entry: &Entry| {
+            (entry.kind == EntryKind::Focus || self.history_show_breaks)
+                && (search.is_empty() || entry.matches(search))
+        };
+
+        // Most recent day first, walking back only as far as the days shown. The
+        // history is kept in chronological order.
+        let mut days: Vec<(NaiveDate, DaySummary, Vec<&Entry>)> = Vec::new();
+        let mut more = false;
+        for (index, entry) in self.history.iter().enumerate().rev() {
+            let date = entry.date();
+            if days.last().is_none_or(|(day, ..)| *day != date) {
+                // Days without any entry shown don't count
+                if days.last().is_some_and(|(.., entries)| entries.is_empty()) {
+                    days.pop();
                 }
-                inner_col = inner_col.push(widget::Space::with_height(5));
-                inner_col = inner_col.push(widget::divider::horizontal::default());
-                inner_col = inner_col.push(widget::Space::with_height(5));
+                if days.len() == self.history_days {
+                    more = self.history[..=index].iter().any(shown);
+                    break;
+                }
+                days.push((date, DaySummary::default(), Vec::new()));
             }
-        } else {
-            inner_col = inner_col.push(widget::text::text(fl!("no-elements")));
+            if let Some((_, summary, entries)) = days.last_mut() {
+                summary.add(entry);
+                if shown(entry) {
+                    entries.push(entry);
+                }
+            }
+        }
+        if days.last().is_some_and(|(.., entries)| entries.is_empty()) {
+            days.pop();
         }
 
+        let mut list = widget::column();
+        if days.is_empty() {
+            list = list.push(widget::text::text(fl!("no-elements")));
+        }
+        for (date, summary, entries) in days {
+            list = list
+                .push(
+                    widget::row()
+                        .push(widget::text::title4(history::format_day(date)).width(Length::Fill))
+                        .push(widget::text::text(
+                            self.format_seconds(summary.focus_seconds),
+                        ))
+                        .align_y(Alignment::Center),
+                )
+                .push_maybe((summary.interruptions() > 0).then(|| {
+                    widget::text::caption(fl!(
+                        "interruptions-summary",
+                        internal = summary.internal_interruptions,
+                        external = summary.external_interruptions
+                    ))
+                }))
+                .push(widget::Space::with_height(5));
+            for entry in entries.into_iter().rev() {
+                list = list.push(self.history_entry_view(entry));
+                list = list.push(widget::Space::with_height(5));
+                list = list.push(widget::divider::horizontal::default());
+                list = list.push(widget::Space::with_height(5));
+            }
+            list = list.push(widget::Space::with_height(10));
+        }
+        if more {
+            list = list.push(
+                widget::button::standard(fl!("history-show-more"))
+                    .on_press(PomodoroMessage::HistoryShowMore),
+            );
+        }
+
+        let inner_col = widget::column()
+            .push(
+                widget::search_input(fl!("history-search"), &self.history_search)
+                    .on_input(PomodoroMessage::HistorySearchChanged)
+                    .on_clear(PomodoroMessage::HistorySearchChanged(String::new())),
+            )
+            .push(widget::Space::with_height(5))
+            .push(
+                widget::checkbox(fl!("history-show-breaks"), self.history_show_breaks)
+                    .on_toggle(PomodoroMessage::HistoryShowBreaksToggled),
+            )
+            .push(widget::Space::with_height(10))
+            .push(list);
+
         widget::column()
             .push(widget::Space::with_height(20))
             .push(
                 widget::column()
                     .width(Length::Fixed(350.))
                     .push(
-                        widget::container(widget::text::text(fl!("history"))).padding(Padding {
+                        widget::container(
+                            widget::row()
+                                .push(widget::text::text(fl!("history")).width(Length::Fill))
+                                .push(
+                                    widget::button::icon(icon::from_name("list-add-symbolic"))
+                                        .tooltip(fl!("entry-add"))
+                                        .on_press(PomodoroMessage::AddEntry),
+                                )
+                                .align_y(Alignment::Center),
+                        )
+                        .padding(Padding {
                             top: 0.,
                             right: 0.,
                             bottom: 0.,
@@ -182,6 +831,48 @@ impl Pomodoro {
             .into()
     }
 
+    fn history_entry_view<'a>(&'a self, entry: &'a Entry) -> Element<'a, PomodoroMessage> {
+        let start = history::local_time(entry.started_at);
+        let end = history::local_time(entry.ended_at);
+
+        widget::row()
+            .push(
+                widget::column()
+                    .push(widget::text::text(entry.kind.label()))
+                    .push(widget::text::caption(format!(
+                        "{:02}:{:02} – {:02}:{:02}",
+                        start.0, start.1, end.0, end.1
+                    )))
+                    .push_maybe(
+                        (!entry.task.is_empty()).then(|| widget::text::caption(&entry.task)),
+                    )
+                    .push_maybe((!entry.interruptions.is_empty()).then(|| {
+                        widget::text::caption(fl!(
+                            "interruptions-count",
+                            count = entry.interruptions.len()
+                        ))
+                    }))
+                    .push_maybe(
+                        (!entry.note.is_empty()).then(|| widget::text::caption(&entry.note)),
+                    )
+                    .push_maybe(
+                        entry
+                            .rating
+                            .map(|rating| widget::text::caption(stars(rating))),
+                    )
+                    .width(Length::Fill),
+            )
+            .push(widget::text::text(self.format_seconds(entry.duration)))
+            .push(
+                widget::button::icon(icon::from_name("document-edit-symbolic"))
+                    .tooltip(fl!("entry-edit"))
+                    .on_press(PomodoroMessage::EditEntry(entry.started_at, entry.ended_at)),
+            )
+            .spacing(5)
+            .align_y(Alignment::Center)
+            .into()
+    }
+
     pub fn update(&mut self, message: PomodoroMessage) -> Task<crate::app::Message> {
         let mut commands = Vec::new();
         match message {
@@ -189,53 +880,24 @@ impl Pomodoro {
                 self.reset_all();
             }
             PomodoroMessage::PomodoroTick => {
-                self.slider_value -= 1.;
+                if self.is_flowing() {
+                    self.flow_seconds += 1;
+                } else {
+                    self.slider_value -= 1.;
 
-                if self.slider_value <= 0. {
-                    if self.pomodoro_completed < self.pomodoro_before_long_pause {
-                        if self.slider_max_value == self.timer_duration as f32 * 60. {
-                            // Pomodoro just finished, start short pause
-                            log::info!("start short pause");
-                            let seconds = self.pause_duration as u32 * 60;
-                            self.slider_value = seconds as f32;
-                            self.slider_max_value = seconds as f32;
-                            self.pomodoro_completed += 1;
-                            self.history
-                                .push(CompletedItem::Pomodoro(self.timer_duration as u32 * 60));
-                        } else {
-                            // Short pause finished, start new pomodoro
-                            log::info!("start new pomodoro");
-                            let seconds = self.timer_duration as u32 * 60;
-                            self.slider_value = seconds as f32;
-                            self.slider_max_value = seconds as f32;
-                            self.history
-                                .push(CompletedItem::Pause(self.pause_duration as u32 * 60));
-                        }
-                    } else {
-                        // After completing the specified number of Pomodoros, start long pause
-                        if self.slider_max_value == self.timer_duration as f32 * 60. {
-                            log::info!("start long pause");
-                            let seconds = self.long_pause_duration as u32 * 60;
-                            self.slider_value = seconds as f32;
-                            self.slider_max_value = seconds as f32;
-                            self.pomodoro_completed = 0;
-                            self.history
-                                .push(CompletedItem::Pomodoro(self.timer_duration as u32 * 60));
-                        } else {
-                            // Long pause finished, start new pomodoro
-                            log::info!("start new pomodoro after long pause");
-                            let seconds = self.timer_duration as u32 * 60;
-                            self.slider_value = seconds as f32;
-                            self.slider_max_value = seconds as f32;
-                            self.history
-                                .push(CompletedItem::Pause(self.long_pause_duration as u32 * 60));
-                        }
+                    if self.slider_value <= 0. {
+                        commands.push(self.advance_phase());
                     }
                 }
             }
             PomodoroMessage::StartPomodoro => {
                 commands.push(Task::perform(async {}, |_| Message::StartPomodoroTimer));
-                self.in_action = true;
+                // Resuming a paused phase doesn't start it again
+                if self.phase_started_at.is_none() {
+                    commands.push(self.emit(self.phase.start_event()));
+                    self.phase_started_at = Some(Status::now());
+                }
+                self.state = RunState::Running;
                 if self.notifications_active {
                     let _ = Notification::new()
                         .summary(&fl!("pomodoro-started"))
@@ -246,6 +908,7 @@ impl Pomodoro {
             }
             PomodoroMessage::PausePomodoro => {
                 commands.push(Task::perform(async {}, |_| Message::PausePomodoroTimer));
+                commands.push(self.emit(TimerEventKind::Paused));
                 if self.notifications_active {
                     let _ = Notification::new()
                         .summary(&fl!("pomodoro-paused"))
@@ -253,7 +916,11 @@ impl Pomodoro {
                         .appname("Chronos")
                         .show();
                 }
-                self.in_action = false;
+                self.state = RunState::Paused;
+                // Leaving the computer is its own reason
+                if !self.phase.is_break() && self.away_since.is_none() {
+                    self.open_interruption_form();
+                }
             }
             PomodoroMessage::ResetPomodoro => {
                 if self.notifications_active {
@@ -263,40 +930,779 @@ impl Pomodoro {
                         .appname("Chronos")
                         .show();
                 }
-                self.reset_all();
+                if self.is_flowing() && self.flow_seconds > 0 {
+                    // Stopping ends a flowtime block, leaving its break to take or not
+                    commands.push(Task::perform(async {}, |_| Message::PausePomodoroTimer));
+                    self.state = RunState::Stopped;
+                    commands.push(self.advance_phase());
+                } else {
+                    commands.push(self.emit(TimerEventKind::Reset));
+                    self.reset_all();
+                }
+            }
+            PomodoroMessage::TaskChanged(task) => {
+                self.task = task;
+            }
+            PomodoroMessage::Away => {
+                // Only focus time is at stake, breaks keep running.
+                if self.is_running() && !self.phase.is_break() {
+                    log::info!("user away, pausing focus block");
+                    self.away_since = Some(Status::now());
+                    return self.update(PomodoroMessage::PausePomodoro);
+                }
+            }
+            PomodoroMessage::Back => {
+                if let Some(since) = self.away_since.take() {
+                    let seconds = Status::now().saturating_sub(since) as u32;
+                    self.returned_after = Some(seconds);
+                    commands.push(Task::perform(ask_away_choice(seconds), |choice| {
+                        Message::Pomodoro(PomodoroMessage::AwayNotificationClosed(choice))
+                    }));
+                }
+            }
+            PomodoroMessage::Skip => {
+                log::info!("skipping {}", self.phase.id());
+                // Flowtime focus blocks are always ended by hand
+                if self.phase.is_break() || self.is_flowing() {
+                    commands.push(self.advance_phase());
+                } else {
+                    commands.push(self.skip_focus());
+                }
+            }
+            PomodoroMessage::PostponeBreak => {
+                if self.can_postpone_break() {
+                    commands.push(self.postpone_break());
+                }
+            }
+            PomodoroMessage::UpdateBreakPostpone => {
+                let config = Config::load().1;
+                self.break_postpone_minutes = config.break_postpone_minutes;
+                self.break_postpone_limit = config.break_postpone_limit;
+            }
+            PomodoroMessage::ModeChanged(mode) => {
+                if self.state == RunState::Stopped && mode != self.mode {
+                    log::info!("switching to {:?} mode", mode);
+                    let mut config = Config::load();
+                    let _ = config.1.set_timer_mode(&config.0.unwrap(), mode);
+                    self.reset_all();
+                }
+            }
+            PomodoroMessage::UpdateFlowtimeBreakRatio => {
+                self.flowtime_break_ratio = Config::load().1.flowtime_break_ratio;
+            }
+            PomodoroMessage::UpdateGoal => {
+                let config = Config::load().1;
+                self.daily_goal = config.daily_goal;
+                self.daily_goal_unit = config.daily_goal_unit;
+            }
+            PomodoroMessage::DailySummary => {
+                self.summary_checked_at = Local::now();
+                self.show_daily_summary();
+            }
+            PomodoroMessage::UpdateDailySummary => {
+                // Only summaries still ahead are shown, not one missed before the change
+                self.summary_checked_at = Local::now();
+            }
+            PomodoroMessage::Interrupt => {
+                if self.can_interrupt() {
+                    self.open_interruption_form();
+                }
+            }
+            PomodoroMessage::InterruptionKindChanged(kind) => {
+                if let Some(interruption) = &mut self.interruption_form {
+                    interruption.kind = kind;
+                }
+            }
+            PomodoroMessage::InterruptionNoteChanged(note) => {
+                if let Some(interruption) = &mut self.interruption_form {
+                    interruption.note = note;
+                }
+            }
+            PomodoroMessage::LogInterruption => {
+                self.log_interruption();
+            }
+            PomodoroMessage::CancelInterruption => {
+                self.interruption_form = None;
+            }
+            PomodoroMessage::EditEntry(started_at, ended_at) => {
+                if let Some(index) = self.entry_position(Some((started_at, ended_at))) {
+                    self.entry_form = Some(EntryForm::new(true, &self.history[index]));
+                }
+            }
+            PomodoroMessage::AddEntry => {
+                let now = Status::now();
+                let duration = self.timer_duration as u32 * 60;
+                let entry = Entry {
+                    kind: EntryKind::Focus,
+                    started_at: now.saturating_sub(duration as u64),
+                    ended_at: now,
+                    duration,
+                    task: self.task.trim().to_string(),
+                    ..Default::default()
+                };
+                self.entry_form = Some(EntryForm::new(false, &entry));
+            }
+            PomodoroMessage::EntryKindChanged(kind) => {
+                if let Some(form) = &mut self.entry_form {
+                    form.kind = kind;
+                }
+            }
+            PomodoroMessage::EntryDateChanged(date) => {
+                if let Some(form) = &mut self.entry_form {
+                    form.date = date;
+                }
+            }
+            PomodoroMessage::EntryMinutesChanged(minutes) => {
+                if let Some(form) = &mut self.entry_form {
+                    form.minutes = minutes;
+                    form.minutes_str = format!("{} {}", minutes, fl!("minutes"));
+                }
+            }
+            PomodoroMessage::EntryEndHourChanged(hour) => {
+                if let Some(form) = &mut self.entry_form {
+                    form.end.0 = hour;
+                    form.end_hour_str = format!("{:02}", hour);
+                }
+            }
+            PomodoroMessage::EntryEndMinuteChanged(minute) => {
+                if let Some(form) = &mut self.entry_form {
+                    form.end.1 = minute;
+                    form.end_minute_str = format!("{:02}", minute);
+                }
+            }
+            PomodoroMessage::EntryTaskChanged(task) => {
+                if let Some(form) = &mut self.entry_form {
+                    form.task = task;
+                }
+            }
+            PomodoroMessage::EntryNoteChanged(note) => {
+                if let Some(form) = &mut self.entry_form {
+                    form.note = note;
+                }
+            }
+            PomodoroMessage::EntryRatingChanged(rating) => {
+                if let Some(form) = &mut self.entry_form {
+                    form.rating = Some(rating);
+                }
+            }
+            PomodoroMessage::SaveEntry => {
+                if let Some(form) = self.entry_form.take() {
+                    self.save_entry(form);
+                }
+            }
+            PomodoroMessage::DeleteEntry => {
+                let editing = self.entry_form.take().and_then(|form| form.editing);
+                if let Some(index) = self.entry_position(editing) {
+                    log::info!("deleting history entry");
+                    self.history.remove(index);
+                    history::save(&self.history);
+                }
+            }
+            PomodoroMessage::CancelEntry => {
+                self.entry_form = None;
+            }
+            PomodoroMessage::HistorySearchChanged(search) => {
+                self.history_search = search;
+                self.history_days = HISTORY_DAYS;
+            }
+            PomodoroMessage::HistoryShowMore => {
+                self.history_days += HISTORY_DAYS;
+            }
+            PomodoroMessage::HistoryShowBreaksToggled(value) => {
+                self.history_show_breaks = value;
+                let mut config = Config::load();
+                let _ = config.1.set_history_show_breaks(&config.0.unwrap(), value);
+            }
+            PomodoroMessage::AwayNotificationClosed(choice) => {
+                if let Some(choice) = choice {
+                    return self.update(PomodoroMessage::AwayChoice(choice));
+                }
+            }
+            PomodoroMessage::AwayChoice(choice) => {
+                let Some(seconds) = self.returned_after.take() else {
+                    return Task::none();
+                };
+                match choice {
+                    AwayChoice::Resume => {}
+                    AwayChoice::Discard => {
+                        log::info!("discarding interrupted focus block");
+                        self.slider_value = self.slider_max_value;
+                        self.flow_seconds = 0;
+                        self.phase_started_at = None;
+                        self.interruptions.clear();
+                        return Task::none();
+                    }
+                    AwayChoice::Count if self.is_flowing() => {
+                        self.flow_seconds += seconds;
+                    }
+                    AwayChoice::Count => {
+                        self.slider_value -= seconds as f32;
+                        if self.slider_value <= 0. {
+                            commands.push(self.advance_phase());
+                        }
+                    }
+                }
+                if !self.is_running() {
+                    commands.push(self.update(PomodoroMessage::StartPomodoro));
+                }
             }
         }
         Task::batch(commands)
     }
 
-    fn reset_all(&mut self) {
-        let config = Config::load().1;
-        let seconds = (config.timer_duration * 60) as f32;
-        self.in_action = false;
+    /// Moves on to the next phase once the current one ran out.
+    fn advance_phase(&mut self) -> Task<Message> {
+        let finished = self.emit(self.phase.end_event());
+        // A reason never given may well be a pause from the command line or the
+        // compact window, where the form can't be seen, so it isn't counted
+        self.interruption_form = None;
+        let elapsed = if self.is_flowing() {
+            self.flow_seconds
+        } else {
+            (self.slider_max_value - self.slider_value.max(0.)) as u32
+        };
+
+        match self.phase {
+            Phase::Focus => {
+                if let Some(postponed) = self.postponed_break.take() {
+                    // The extra focus time belongs to the block the break was postponed from
+                    log::info!("start postponed break");
+                    let last_focus = self.last_focus.and_then(|started_at| {
+                        self.history.iter_mut().find(|entry| {
+                            entry.kind == EntryKind::Focus && entry.started_at == started_at
+                        })
+                    });
+                    match last_focus {
+                        Some(entry) => {
+                            entry.duration += elapsed;
+                            entry.ended_at = Status::now();
+                            entry.interruptions.append(&mut self.interruptions);
+                            self.history.sort_by_key(|entry| entry.ended_at);
+                            history::save(&self.history);
+                        }
+                        // Deleted from the history in the meantime
+                        None => self.record(EntryKind::Focus, elapsed),
+                    }
+                    self.phase = postponed;
+                } else if self.mode == TimerMode::Flowtime {
+                    self.record(EntryKind::Focus, elapsed);
+                    self.flow_break =
+                        (elapsed / self.flowtime_break_ratio.max(1)).max(FLOWTIME_MIN_BREAK);
+                    log::info!("start flowtime break of {} seconds", self.flow_break);
+                    self.phase = Phase::ShortBreak;
+                } else {
+                    self.record(EntryKind::Focus, elapsed);
+                    if self.pomodoro_completed < self.pomodoro_before_long_pause {
+                        log::info!("start short pause");
+                        self.pomodoro_completed += 1;
+                        self.phase = Phase::ShortBreak;
+                    } else {
+                        // After completing the specified number of Pomodoros, start long pause
+                        log::info!("start long pause");
+                        self.pomodoro_completed = 0;
+                        self.phase = Phase::LongBreak;
+                    }
+                }
+            }
+            Phase::ShortBreak | Phase::LongBreak => {
+                if self.phase == Phase::ShortBreak {
+                    log::info!("start new pomodoro");
+                } else {
+                    log::info!("start new pomodoro after long pause");
+                }
+                // A break skipped before it began leaves nothing worth recording
+                if elapsed > 0 {
+                    self.record(EntryKind::Break, elapsed);
+                }
+                self.postpones_used = 0;
+                self.flow_seconds = 0;
+                self.phase = Phase::Focus;
+            }
+        }
+
+        Task::batch(vec![finished, self.begin_phase()])
+    }
+
+    /// Leaves a focus block before it ran out, without counting it.
+    fn skip_focus(&mut self) -> Task<Message> {
+        let finished = self.emit(self.phase.end_event());
+        self.interruption_form = None;
+        self.interruptions.clear();
+        self.phase = self.postponed_break.take().unwrap_or(Phase::ShortBreak);
+
+        Task::batch(vec![finished, self.begin_phase()])
+    }
+
+    /// Sets the timer for the phase just moved to.
+    fn begin_phase(&mut self) -> Task<Message> {
+        let seconds = self.phase_duration() as f32;
+        self.slider_value = seconds;
+        self.slider_max_value = seconds;
+        self.phase_started_at = self.is_running().then(Status::now);
+
+        // A phase reached while stopped or paused starts once the timer does
+        if self.is_running() {
+            self.emit(self.phase.start_event())
+        } else {
+            Task::none()
+        }
+    }
+
+    /// Adds the phase that just ended to the history.
+    fn record(&mut self, kind: EntryKind, elapsed: u32) {
+        let now = Status::now();
+        let today = history::today();
+        let before = DaySummary::new(&self.history, today).progress(self.daily_goal_unit);
+
+        let started_at = self
+            .phase_started_at
+            .unwrap_or_else(|| now.saturating_sub(elapsed as u64));
+        if kind == EntryKind::Focus {
+            self.last_focus = Some(started_at);
+        }
+        self.history.push(Entry {
+            kind,
+            started_at,
+            ended_at: now,
+            duration: elapsed,
+            task: self.task.trim().to_string(),
+            interruptions: std::mem::take(&mut self.interruptions),
+            ..Default::default()
+        });
+        history::save(&self.history);
+
+        // Don't replace an entry the user is still writing
+        if kind == EntryKind::Focus
+            && self.entry_form.is_none()
+            && Config::load().1.reflection_prompt
+        {
+            let mut form = EntryForm::new(true, &self.history[self.history.len() - 1]);
+            form.reflecting = true;
+            self.entry_form = Some(form);
+        }
+
+        let after = DaySummary::new(&self.history, today).progress(self.daily_goal_unit);
+        if self.daily_goal > 0 && before < self.daily_goal && after >= self.daily_goal {
+            log::info!("daily goal reached");
+            if self.notifications_active {
+                let _ = Notification::new()
+                    .summary(&fl!("daily-goal-reached"))
+                    .body(&fl!(
+                        "daily-goal-reached-des",
+                        goal = format!("{} {}", self.daily_goal, self.daily_goal_unit.label())
+                    ))
+                    .appname("Chronos")
+                    .show();
+            }
+        }
+    }
+
+    /// Position in the history of the entry with these start and end times.
+    fn entry_position(&self, times: Option<(u64, u64)>) -> Option<usize> {
+        let (started_at, ended_at) = times?;
+        self.history
+            .iter()
+            .position(|entry| entry.started_at == started_at && entry.ended_at == ended_at)
+    }
+
+    /// Applies the changes of `form` to its entry, or adds it as a new one.
+    fn save_entry(&mut self, form: EntryForm) {
+        let index = self.entry_position(form.editing);
+        let existing = index.map(|index| &self.history[index]);
+        let entry = form.entry(existing, Status::now());
+        // A postponed break keeps extending the block even once its times are corrected
+        if existing.is_some_and(|existing| Some(existing.started_at) == self.last_focus) {
+            self.last_focus = Some(entry.started_at);
+        }
+
+        match index {
+            Some(index) => self.history[index] = entry,
+            None => {
+                log::info!("adding history entry");
+                self.history.push(entry);
+            }
+        }
+        self.history.sort_by_key(|entry| entry.ended_at);
+        history::save(&self.history);
+    }
+
+    /// Notifies the totals of the day and the tasks worked on.
+    fn show_daily_summary(&self) {
+        let summary = DaySummary::new(&self.history, history::today());
+        let mut body = fl!(
+            "daily-summary-des",
+            blocks = summary.focus_blocks,
+            focus = format_duration(summary.focus_seconds),
+            breaks = format_duration(summary.break_seconds)
+        );
+        if summary.interruptions() > 0 {
+            body.push('\n');
+            body.push_str(&fl!(
+                "interruptions-summary",
+                internal = summary.internal_interruptions,
+                external = summary.external_interruptions
+            ));
+        }
+        if !summary.tasks.is_empty() {
+            body.push('\n');
+            body.push_str(&fl!(
+                "daily-summary-tasks",
+                tasks = summary.tasks.join(", ")
+            ));
+        }
+        let _ = Notification::new()
+            .summary(&fl!("daily-summary"))
+            .body(&body)
+            .appname("Chronos")
+            .show();
+    }
+
+    /// Goes back to focusing for a while before taking the current break.
+    fn postpone_break(&mut self) -> Task<Message> {
+        let finished = self.emit(self.phase.end_event());
+
+        log::info!(
+            "postponing break by {} minutes",
+            self.break_postpone_minutes
+        );
+        self.postpones_used += 1;
+        self.postponed_break = Some(self.phase);
+        self.phase = Phase::Focus;
+        let seconds = (self.break_postpone_minutes * 60) as f32;
         self.slider_value = seconds;
         self.slider_max_value = seconds;
+
+        let started = self.emit(self.phase.start_event());
+
+        Task::batch(vec![finished, started])
+    }
+
+    pub fn can_postpone_break(&self) -> bool {
+        self.mode == TimerMode::Pomodoro
+            && self.phase.is_break()
+            && self.postpones_used < self.break_postpone_limit
+    }
+
+    /// Whether an interruption can be logged, only during a started focus block.
+    fn can_interrupt(&self) -> bool {
+        !self.phase.is_break()
+            && self.state != RunState::Stopped
+            && self.interruption_form.is_none()
+    }
+
+    fn open_interruption_form(&mut self) {
+        self.interruption_form = Some(Interruption {
+            at: Status::now(),
+            ..Default::default()
+        });
+    }
+
+    fn log_interruption(&mut self) {
+        if let Some(mut interruption) = self.interruption_form.take() {
+            log::info!("logging {:?} interruption", interruption.kind);
+            interruption.note = interruption.note.trim().to_string();
+            self.interruptions.push(interruption);
+        }
+    }
+
+    /// Whether the current focus block counts up instead of down.
+    fn is_flowing(&self) -> bool {
+        self.mode == TimerMode::Flowtime && self.phase == Phase::Focus
+    }
+
+    /// Length of the current phase in seconds, zero for a flowtime focus block.
+    fn phase_duration(&self) -> u32 {
+        if self.mode == TimerMode::Flowtime {
+            return match self.phase {
+                Phase::Focus => 0,
+                Phase::ShortBreak | Phase::LongBreak => self.flow_break,
+            };
+        }
+        let minutes = match self.phase {
+            Phase::Focus => self.timer_duration,
+            Phase::ShortBreak => self.pause_duration,
+            Phase::LongBreak => self.long_pause_duration,
+        };
+        minutes as u32 * 60
+    }
+
+    /// Hands an event about the current phase over to the application.
+    fn emit(&self, kind: TimerEventKind) -> Task<Message> {
+        let event = TimerEvent {
+            kind,
+            phase: self.phase,
+            duration: if self.is_flowing() {
+                self.flow_seconds
+            } else {
+                self.slider_max_value as u32
+            },
+            task: self.task.clone(),
+            cycle: self.pomodoro_completed,
+        };
+        Task::perform(async {}, move |_| Message::TimerEvent(event))
+    }
+
+    /// Snapshot of the timer for the `chronos status` command.
+    pub fn status(&self) -> Status {
+        Status {
+            pid: std::process::id(),
+            phase: self.phase,
+            running: self.is_running(),
+            remaining: if self.is_flowing() {
+                self.flow_seconds
+            } else {
+                self.slider_value as u32
+            },
+            duration: self.slider_max_value as u32,
+            cycle: self.pomodoro_completed,
+            cycles: self.pomodoro_before_long_pause,
+            task: self.task.clone(),
+            counting_up: self.is_flowing(),
+            updated_at: Status::now(),
+        }
+    }
+
+    pub fn is_running(&self) -> bool {
+        self.state == RunState::Running
+    }
+
+    /// Unix time of the next end-of-day summary shown at `time`.
+    pub fn next_summary_at(&self, time: (u32, u32)) -> Option<i64> {
+        history::next_summary_at(time, self.summary_checked_at)
+    }
+
+    /// Completed focus blocks and breaks, oldest first.
+    pub fn history(&self) -> &[Entry] {
+        &self.history
+    }
+
+    fn reset_all(&mut self) {
+        let config = Config::load().1;
+        self.state = RunState::Stopped;
+        self.mode = config.timer_mode;
+        self.phase = Phase::Focus;
+        self.away_since = None;
+        self.returned_after = None;
         self.timer_duration = config.timer_duration as f32;
         self.pause_duration = config.pause_duration as f32;
         self.long_pause_duration = config.long_pause_duration as f32;
         self.pomodoro_completed = 0;
         self.pomodoro_before_long_pause = config.pomodoro_before_long_pause;
-        self.history = Vec::new();
+        self.phase_started_at = None;
+        self.daily_goal = config.daily_goal;
+        self.daily_goal_unit = config.daily_goal_unit;
+        self.interruptions = Vec::new();
+        self.interruption_form = None;
         self.notifications_active = config.notifications_active;
+        self.postponed_break = None;
+        self.last_focus = None;
+        self.postpones_used = 0;
+        self.break_postpone_minutes = config.break_postpone_minutes;
+        self.break_postpone_limit = config.break_postpone_limit;
+        self.flow_seconds = 0;
+        self.flow_break = 0;
+        self.flowtime_break_ratio = config.flowtime_break_ratio;
+
+        let seconds = self.phase_duration() as f32;
+        self.slider_value = seconds;
+        self.slider_max_value = seconds;
     }
 
-    fn format_slider_value(&self) -> String {
-        let hours = self.slider_value as u32 / 3600;
-        let minutes = (self.slider_value as u32 % 3600) / 60;
-        let remaining_seconds = self.slider_value as u32 % 60;
+    /// Share of the phase left, or of the hour passed while counting up.
+    fn progress(&self) -> f32 {
+        if self.is_flowing() {
+            (self.flow_seconds % 3600) as f32 / 3600.
+        } else if self.slider_max_value > 0. {
+            (self.slider_value / self.slider_max_value).clamp(0., 1.)
+        } else {
+            0.
+        }
+    }
+
+    /// Ring around the countdown, emptying as the phase runs out.
+    fn ring_svg(&self) -> String {
+        let color = phase_color(self.phase);
+        let radius = (RING_SIZE - RING_WIDTH) / 2.;
+        let circumference = 2. * std::f32::consts::PI * radius;
+        let progress = self.progress();
+
+        format!(
+            r#"<svg xmlns="http://www.w3.org/2000/svg" width="{size}" height="{size}" viewBox="0 0 {size} {size}">
+<circle cx="{center}" cy="{center}" r="{radius}" fill="none" stroke="{color}" stroke-opacity="0.2" stroke-width="{width}"/>
+<circle cx="{center}" cy="{center}" r="{radius}" fill="none" stroke="{color}" stroke-width="{width}" stroke-linecap="round" stroke-dasharray="{filled} {circumference}" transform="rotate(-90 {center} {center})"/>
+</svg>"#,
+            size = RING_SIZE,
+            center = RING_SIZE / 2.,
+            width = RING_WIDTH,
+            filled = circumference * progress,
+        )
+    }
+
+    /// One dot per focus block until the long break, filled once completed.
+    fn cycle_svg(&self) -> String {
+        // A long break comes after the focus block following the last short break
+        let blocks = self.pomodoro_before_long_pause + 1;
+        let completed = match self.phase {
+            Phase::LongBreak => blocks,
+            _ => self.pomodoro_completed,
+        };
+        let color = phase_color(self.phase);
+        let step = CYCLE_DOT * 2.;
+        let radius = CYCLE_DOT / 2. - 1.;
+
+        let mut dots = String::new();
+        for index in 0..blocks {
+            let opacity = if index < completed { 1. } else { 0.2 };
+            dots.push_str(&format!(
+                r#"<circle cx="{}" cy="{}" r="{radius}" fill="{color}" fill-opacity="{opacity}"/>"#,
+                index as f32 * step + CYCLE_DOT / 2.,
+                CYCLE_DOT / 2.,
+            ));
+        }
 
-        format!("{:02}:{:02}:{:02}", hours, minutes, remaining_seconds)
+        let width = blocks as f32 * step - CYCLE_DOT;
+        format!(
+            r#"<svg xmlns="http://www.w3.org/2000/svg" width="{width}" height="{CYCLE_DOT}" viewBox="0 0 {width} {CYCLE_DOT}">{dots}</svg>"#
+        )
+    }
+
+    fn format_slider_value(&self) -> String {
+        if self.is_flowing() {
+            return format_duration(self.flow_seconds);
+        }
+        format_duration(self.slider_value as u32)
     }
 
     fn format_seconds(&self, seconds: u32) -> String {
-        let hours = seconds / 3600;
-        let minutes = (seconds % 3600) / 60;
-        let remaining_seconds = seconds % 60;
+        format_duration(seconds)
+    }
+}
+
+/// A rating out of five as stars.
+fn stars(rating: u8) -> String {
+    let rating = rating.min(5) as usize;
+    format!("{}{}", "★".repeat(rating), "☆".repeat(5 - rating))
+}
+
+/// Asks through a notification what to do with the time spent away.
+///
+/// Resolves to `None` when the notification is dismissed or can't be shown.
+async fn ask_away_choice(seconds: u32) -> Option<AwayChoice> {
+    let handle = Notification::new()
+        .summary(&fl!("away-returned"))
+        .body(&fl!(
+            "away-returned-des",
+            duration = format_duration(seconds)
+        ))
+        .action("resume", &fl!("away-resume"))
+        .action("count", &fl!("away-count"))
+        .action("discard", &fl!("away-discard"))
+        .appname("Chronos")
+        .show()
+        .map_err(|err| log::warn!("failed to show notification: {err}"))
+        .ok()?;
+
+    tokio::task::spawn_blocking(move || {
+        let mut choice = None;
+        handle.wait_for_action(|action| {
+            choice = match action {
+                "resume" => Some(AwayChoice::Resume),
+                "count" => Some(AwayChoice::Count),
+                "discard" => Some(AwayChoice::Discard),
+                _ => None,
+            }
+        });
+        choice
+    })
+    .await
+    .ok()
+    .flatten()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn monday() -> NaiveDate {
+        NaiveDate::from_ymd_opt(2024, 1, 15).unwrap()
+    }
+
+    fn focus_block() -> Entry {
+        // 10:00:30, with seconds the form doesn't show
+        let ended_at = history::local_timestamp(monday(), (10, 0)).unwrap() + 30;
+        Entry {
+            started_at: ended_at - 1510,
+            ended_at,
+            duration: 1510,
+            task: "Write report".into(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn keeps_the_exact_times_unless_changed() {
+        let existing = focus_block();
+        let mut form = EntryForm::new(true, &existing);
+        form.note = " Done ".into();
+        form.rating = Some(4);
+
+        let entry = form.entry(Some(&existing), existing.ended_at + 3600);
+
+        assert_eq!(
+            entry,
+            Entry {
+                note: "Done".into(),
+                rating: Some(4),
+                ..existing
+            }
+        );
+    }
+
+    #[test]
+    fn moves_the_start_with_the_changed_times() {
+        let existing = focus_block();
+        let mut form = EntryForm::new(true, &existing);
+        form.minutes = 20;
+        form.end = (9, 45);
+
+        let entry = form.entry(Some(&existing), existing.ended_at + 3600);
+
+        let ended_at = history::local_timestamp(monday(), (9, 45)).unwrap();
+        assert_eq!(entry.ended_at, ended_at);
+        assert_eq!(entry.started_at, ended_at - 1200);
+        assert_eq!(entry.duration, 1200);
+        assert_eq!(entry.task, "Write report");
+    }
+
+    #[test]
+    fn moves_the_entry_to_another_day() {
+        let existing = focus_block();
+        let mut form = EntryForm::new(true, &existing);
+        form.date = monday().pred_opt().unwrap();
+
+        let entry = form.entry(Some(&existing), existing.ended_at + 3600);
+
+        let ended_at = history::local_timestamp(form.date, (10, 0)).unwrap();
+        assert_eq!(entry.date(), form.date);
+        assert_eq!(entry.ended_at, ended_at);
+        assert_eq!(entry.started_at, ended_at - 1500);
+    }
+
+    #[test]
+    fn new_entries_end_on_their_day_and_not_after_now() {
+        let now = history::local_timestamp(monday(), (12, 0)).unwrap();
+        let mut form = EntryForm::new(false, &Entry::default());
+        form.date = monday();
+        form.minutes = 25;
+        form.end = (11, 30);
+
+        let entry = form.entry(None, now);
+        assert_eq!(entry.date(), monday());
+        assert_eq!(entry.ended_at, now - 1800);
 
-        format!("{:02}:{:02}:{:02}", hours, minutes, remaining_seconds)
+        form.end = (18, 0);
+        let entry = form.entry(None, now);
+        assert_eq!(entry.ended_at, now);
+        assert_eq!(entry.started_at, now - 1500);
     }
 }
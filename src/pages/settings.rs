@@ -1,3 +1,11 @@
+use std::collections::BTreeMap;
+
+use crate::config::Hooks;
+use crate::core::ambience::Ambience;
+use crate::core::history::GoalUnit;
+use crate::core::shortcuts::{self, Shortcut};
+use crate::core::sound::Sound;
+use crate::core::timer::TimerEventKind;
 use crate::{app::Message, config::Config, fl};
 use cosmic::iced_core::alignment::Horizontal;
 use cosmic::{
@@ -13,6 +21,36 @@ pub enum SettingsMessage {
     LongPauseDurationChanged(f32),
     PomodoroBeforeLongPauseChanged(u32),
     NotificationToggle(bool),
+    HookChanged(TimerEventKind, String),
+    HookTimeoutChanged(u32),
+    WebhookUrlChanged(usize, String),
+    AddWebhook,
+    RemoveWebhook(usize),
+    WebhookTimeoutChanged(u32),
+    WebhookRetriesChanged(u32),
+    DoNotDisturbToggle(bool),
+    InhibitSleepToggle(bool),
+    AutoPauseOnLockToggle(bool),
+    AutoPauseOnIdleToggle(bool),
+    ReflectionPromptToggle(bool),
+    SoundsToggle(bool),
+    SoundFileChanged(Sound, String),
+    SoundVolumeChanged(f32),
+    PreviewSound(Sound),
+    AmbienceSelected(usize),
+    AmbienceVolumeChanged(f32),
+    BreakOverlayToggle(bool),
+    BreakPostponeMinutesChanged(u32),
+    BreakPostponeLimitChanged(u32),
+    FlowtimeBreakRatioChanged(u32),
+    DailyGoalChanged(u32),
+    DailyGoalUnitSelected(usize),
+    DailySummaryToggle(bool),
+    DailySummaryHourChanged(u32),
+    DailySummaryMinuteChanged(u32),
+    CompactHideHeaderToggle(bool),
+    TitleCountdownToggle(bool),
+    ShortcutChanged(Shortcut, String),
 }
 
 pub struct Settings {
@@ -22,6 +60,44 @@ pub struct Settings {
     pomodoro_before_long_pause_str: String,
     pomodoro_before_long_pause: u32,
     notification_active: bool,
+    hooks: Hooks,
+    hook_timeout: u32,
+    hook_timeout_str: String,
+    webhook_urls: Vec<String>,
+    webhook_timeout: u32,
+    webhook_timeout_str: String,
+    webhook_retries: u32,
+    webhook_retries_str: String,
+    do_not_disturb: bool,
+    inhibit_sleep: bool,
+    auto_pause_on_lock: bool,
+    auto_pause_on_idle: bool,
+    reflection_prompt: bool,
+    sounds_active: bool,
+    focus_end_sound: String,
+    break_end_sound: String,
+    sound_volume: f32,
+    ambience: Ambience,
+    ambience_names: Vec<String>,
+    ambience_volume: f32,
+    break_overlay: bool,
+    break_postpone_minutes: u32,
+    break_postpone_minutes_str: String,
+    break_postpone_limit: u32,
+    break_postpone_limit_str: String,
+    flowtime_break_ratio: u32,
+    flowtime_break_ratio_str: String,
+    daily_goal: u32,
+    daily_goal_str: String,
+    daily_goal_unit: GoalUnit,
+    goal_unit_names: Vec<String>,
+    daily_summary: bool,
+    daily_summary_time: (u32, u32),
+    daily_summary_hour_str: String,
+    daily_summary_minute_str: String,
+    compact_hide_header: bool,
+    title_countdown: bool,
+    shortcuts: BTreeMap<Shortcut, String>,
 }
 
 impl Default for Settings {
@@ -35,6 +111,48 @@ impl Default for Settings {
             pomodoro_before_long_pause: config.pomodoro_before_long_pause,
             pomodoro_before_long_pause_str: config.pomodoro_before_long_pause.to_string(),
             notification_active: config.notifications_active,
+            hooks: config.hooks,
+            hook_timeout: config.hook_timeout,
+            hook_timeout_str: format!("{} {}", config.hook_timeout, fl!("seconds")),
+            webhook_urls: config.webhook_urls,
+            webhook_timeout: config.webhook_timeout,
+            webhook_timeout_str: format!("{} {}", config.webhook_timeout, fl!("seconds")),
+            webhook_retries: config.webhook_retries,
+            webhook_retries_str: config.webhook_retries.to_string(),
+            do_not_disturb: config.do_not_disturb,
+            inhibit_sleep: config.inhibit_sleep,
+            auto_pause_on_lock: config.auto_pause_on_lock,
+            auto_pause_on_idle: config.auto_pause_on_idle,
+            reflection_prompt: config.reflection_prompt,
+            sounds_active: config.sounds_active,
+            focus_end_sound: config.focus_end_sound,
+            break_end_sound: config.break_end_sound,
+            sound_volume: config.sound_volume as f32,
+            ambience: config.ambience,
+            ambience_names: Ambience::all().iter().map(ambience_label).collect(),
+            ambience_volume: config.ambience_volume as f32,
+            break_overlay: config.break_overlay,
+            break_postpone_minutes: config.break_postpone_minutes,
+            break_postpone_minutes_str: format!(
+                "{} {}",
+                config.break_postpone_minutes,
+                fl!("minutes")
+            ),
+            break_postpone_limit: config.break_postpone_limit,
+            break_postpone_limit_str: config.break_postpone_limit.to_string(),
+            flowtime_break_ratio: config.flowtime_break_ratio,
+            flowtime_break_ratio_str: format!("1/{}", config.flowtime_break_ratio),
+            daily_goal: config.daily_goal,
+            daily_goal_str: config.daily_goal.to_string(),
+            daily_goal_unit: config.daily_goal_unit,
+            goal_unit_names: GoalUnit::all().iter().map(GoalUnit::label).collect(),
+            daily_summary: config.daily_summary,
+            daily_summary_time: config.daily_summary_time,
+            daily_summary_hour_str: format!("{:02}", config.daily_summary_time.0),
+            daily_summary_minute_str: format!("{:02}", config.daily_summary_time.1),
+            compact_hide_header: config.compact_hide_header,
+            title_countdown: config.title_countdown,
+            shortcuts: config.shortcuts,
         }
     }
 }
@@ -44,12 +162,30 @@ impl Settings {
         let mut col = widget::column();
 
         col = col.push(self.timer_view());
+        col = col.push(widget::Space::with_height(20));
+        col = col.push(self.sounds_view());
+        col = col.push(widget::Space::with_height(20));
+        col = col.push(self.focus_view());
+        col = col.push(widget::Space::with_height(20));
+        col = col.push(self.breaks_view());
+        col = col.push(widget::Space::with_height(20));
+        col = col.push(self.goal_view());
+        col = col.push(widget::Space::with_height(20));
+        col = col.push(self.window_view());
+        col = col.push(widget::Space::with_height(20));
+        col = col.push(self.shortcuts_view());
+        col = col.push(widget::Space::with_height(20));
+        col = col.push(self.hooks_view());
+        col = col.push(widget::Space::with_height(20));
+        col = col.push(self.webhooks_view());
 
-        widget::container(col)
-            .width(Length::Fill)
-            .height(Length::Shrink)
-            .align_x(Horizontal::Center)
-            .into()
+        widget::scrollable(
+            widget::container(col)
+                .width(Length::Fill)
+                .height(Length::Shrink)
+                .align_x(Horizontal::Center),
+        )
+        .into()
     }
 
     pub fn timer_view<'a>(&'a self) -> Element<'a, SettingsMessage> {
@@ -183,6 +319,492 @@ impl Settings {
         element.into()
     }
 
+    pub fn sounds_view<'a>(&'a self) -> Element<'a, SettingsMessage> {
+        let sound_row = |label: String, sound: Sound, file: &'a str| {
+            widget::column().push(widget::text::text(label)).push(
+                widget::row()
+                    .push(
+                        widget::text_input(fl!("sound-default"), file)
+                            .on_input(move |file| SettingsMessage::SoundFileChanged(sound, file))
+                            .width(Length::Fill),
+                    )
+                    .push(
+                        widget::button::icon(widget::icon::from_name(
+                            "media-playback-start-symbolic",
+                        ))
+                        .tooltip(fl!("sound-preview"))
+                        .on_press(SettingsMessage::PreviewSound(sound)),
+                    )
+                    .align_y(iced::Alignment::Center)
+                    .spacing(10),
+            )
+        };
+
+        widget::settings::section()
+            .title(fl!("sounds"))
+            .add(
+                widget::column()
+                    .width(Length::Fill)
+                    .push(widget::text::text(fl!("activate-sounds")))
+                    .push(
+                        widget::toggler(self.sounds_active)
+                            .on_toggle(SettingsMessage::SoundsToggle),
+                    ),
+            )
+            .add(sound_row(
+                fl!("sound-focus-end"),
+                Sound::FocusEnd,
+                &self.focus_end_sound,
+            ))
+            .add(sound_row(
+                fl!("sound-break-end"),
+                Sound::BreakEnd,
+                &self.break_end_sound,
+            ))
+            .add(
+                widget::column()
+                    .push(
+                        widget::row()
+                            .push(
+                                widget::column()
+                                    .push(widget::text::text(fl!("sound-volume")))
+                                    .width(Length::Fill),
+                            )
+                            .push(
+                                widget::column()
+                                    .push(widget::text::text(format!("{}%", self.sound_volume)))
+                                    .width(Length::Fill)
+                                    .align_x(iced::Alignment::End),
+                            ),
+                    )
+                    .push(
+                        widget::slider(
+                            0.0..=100.0,
+                            self.sound_volume,
+                            SettingsMessage::SoundVolumeChanged,
+                        )
+                        .width(Length::Fill)
+                        .height(38),
+                    ),
+            )
+            .add(
+                widget::row()
+                    .push(
+                        widget::column()
+                            .push(widget::text::text(fl!("ambience")))
+                            .width(Length::Fill),
+                    )
+                    .push(widget::dropdown(
+                        &self.ambience_names,
+                        Ambience::all()
+                            .iter()
+                            .position(|ambience| *ambience == self.ambience),
+                        SettingsMessage::AmbienceSelected,
+                    ))
+                    .align_y(iced::Alignment::Center),
+            )
+            .add(
+                widget::column()
+                    .push(
+                        widget::row()
+                            .push(
+                                widget::column()
+                                    .push(widget::text::text(fl!("ambience-volume")))
+                                    .width(Length::Fill),
+                            )
+                            .push(
+                                widget::column()
+                                    .push(widget::text::text(format!("{}%", self.ambience_volume)))
+                                    .width(Length::Fill)
+                                    .align_x(iced::Alignment::End),
+                            ),
+                    )
+                    .push(
+                        widget::slider(
+                            0.0..=100.0,
+                            self.ambience_volume,
+                            SettingsMessage::AmbienceVolumeChanged,
+                        )
+                        .width(Length::Fill)
+                        .height(38),
+                    ),
+            )
+            .into()
+    }
+
+    pub fn focus_view<'a>(&'a self) -> Element<'a, SettingsMessage> {
+        widget::settings::section()
+            .title(fl!("focus-blocks"))
+            .add(
+                widget::column()
+                    .width(Length::Fill)
+                    .push(widget::text::text(fl!("do-not-disturb")))
+                    .push(
+                        widget::toggler(self.do_not_disturb)
+                            .on_toggle(SettingsMessage::DoNotDisturbToggle),
+                    ),
+            )
+            .add(
+                widget::column()
+                    .width(Length::Fill)
+                    .push(widget::text::text(fl!("inhibit-sleep")))
+                    .push(
+                        widget::toggler(self.inhibit_sleep)
+                            .on_toggle(SettingsMessage::InhibitSleepToggle),
+                    ),
+            )
+            .add(
+                widget::column()
+                    .width(Length::Fill)
+                    .push(widget::text::text(fl!("auto-pause-on-lock")))
+                    .push(
+                        widget::toggler(self.auto_pause_on_lock)
+                            .on_toggle(SettingsMessage::AutoPauseOnLockToggle),
+                    ),
+            )
+            .add(
+                widget::column()
+                    .width(Length::Fill)
+                    .push(widget::text::text(fl!("auto-pause-on-idle")))
+                    .push(
+                        widget::toggler(self.auto_pause_on_idle)
+                            .on_toggle(SettingsMessage::AutoPauseOnIdleToggle),
+                    ),
+            )
+            .add(
+                widget::column()
+                    .width(Length::Fill)
+                    .push(widget::text::text(fl!("reflection-prompt")))
+                    .push(
+                        widget::toggler(self.reflection_prompt)
+                            .on_toggle(SettingsMessage::ReflectionPromptToggle),
+                    ),
+            )
+            .into()
+    }
+
+    pub fn breaks_view<'a>(&'a self) -> Element<'a, SettingsMessage> {
+        widget::settings::section()
+            .title(fl!("breaks"))
+            .add(
+                widget::column()
+                    .width(Length::Fill)
+                    .push(widget::text::text(fl!("break-overlay")))
+                    .push(
+                        widget::toggler(self.break_overlay)
+                            .on_toggle(SettingsMessage::BreakOverlayToggle),
+                    ),
+            )
+            .add(
+                widget::row()
+                    .push(
+                        widget::column()
+                            .push(widget::text::text(fl!("break-postpone-minutes")))
+                            .width(Length::Fill),
+                    )
+                    .push(
+                        widget::column()
+                            .push(widget::spin_button(
+                                self.break_postpone_minutes_str.clone(),
+                                self.break_postpone_minutes,
+                                1,
+                                1,
+                                30,
+                                SettingsMessage::BreakPostponeMinutesChanged,
+                            ))
+                            .width(Length::Fill)
+                            .align_x(iced::Alignment::End),
+                    ),
+            )
+            .add(
+                widget::row()
+                    .push(
+                        widget::column()
+                            .push(widget::text::text(fl!("break-postpone-limit")))
+                            .width(Length::Fill),
+                    )
+                    .push(
+                        widget::column()
+                            .push(widget::spin_button(
+                                self.break_postpone_limit_str.clone(),
+                                self.break_postpone_limit,
+                                1,
+                                0,
+                                10,
+                                SettingsMessage::BreakPostponeLimitChanged,
+                            ))
+                            .width(Length::Fill)
+                            .align_x(iced::Alignment::End),
+                    ),
+            )
+            .add(
+                widget::row()
+                    .push(
+                        widget::column()
+                            .push(widget::text::text(fl!("flowtime-break-ratio")))
+                            .width(Length::Fill),
+                    )
+                    .push(
+                        widget::column()
+                            .push(widget::spin_button(
+                                self.flowtime_break_ratio_str.clone(),
+                                self.flowtime_break_ratio,
+                                1,
+                                2,
+                                10,
+                                SettingsMessage::FlowtimeBreakRatioChanged,
+                            ))
+                            .width(Length::Fill)
+                            .align_x(iced::Alignment::End),
+                    ),
+            )
+            .into()
+    }
+
+    pub fn goal_view<'a>(&'a self) -> Element<'a, SettingsMessage> {
+        widget::settings::section()
+            .title(fl!("daily-goal"))
+            .add(
+                widget::row()
+                    .push(
+                        widget::column()
+                            .push(widget::text::text(fl!("daily-goal-amount")))
+                            .push(widget::text::caption(fl!("daily-goal-amount-des")))
+                            .width(Length::Fill),
+                    )
+                    .push(widget::spin_button(
+                        self.daily_goal_str.clone(),
+                        self.daily_goal,
+                        1,
+                        0,
+                        1440,
+                        SettingsMessage::DailyGoalChanged,
+                    ))
+                    .push(widget::dropdown(
+                        &self.goal_unit_names,
+                        GoalUnit::all()
+                            .iter()
+                            .position(|unit| *unit == self.daily_goal_unit),
+                        SettingsMessage::DailyGoalUnitSelected,
+                    ))
+                    .spacing(5)
+                    .align_y(iced::Alignment::Center),
+            )
+            .add(
+                widget::column()
+                    .width(Length::Fill)
+                    .push(widget::text::text(fl!("daily-summary")))
+                    .push(
+                        widget::toggler(self.daily_summary)
+                            .on_toggle(SettingsMessage::DailySummaryToggle),
+                    ),
+            )
+            .add(
+                widget::row()
+                    .push(
+                        widget::column()
+                            .push(widget::text::text(fl!("daily-summary-time")))
+                            .width(Length::Fill),
+                    )
+                    .push(widget::spin_button(
+                        self.daily_summary_hour_str.clone(),
+                        self.daily_summary_time.0,
+                        1,
+                        0,
+                        23,
+                        SettingsMessage::DailySummaryHourChanged,
+                    ))
+                    .push(widget::text::text(":"))
+                    .push(widget::spin_button(
+                        self.daily_summary_minute_str.clone(),
+                        self.daily_summary_time.1,
+                        5,
+                        0,
+                        55,
+                        SettingsMessage::DailySummaryMinuteChanged,
+                    ))
+                    .spacing(5)
+                    .align_y(iced::Alignment::Center),
+            )
+            .into()
+    }
+
+    pub fn window_view<'a>(&'a self) -> Element<'a, SettingsMessage> {
+        widget::settings::section()
+            .title(fl!("window"))
+            .add(
+                widget::column()
+                    .width(Length::Fill)
+                    .push(widget::text::text(fl!("compact-hide-header")))
+                    .push(
+                        widget::toggler(self.compact_hide_header)
+                            .on_toggle(SettingsMessage::CompactHideHeaderToggle),
+                    ),
+            )
+            .add(
+                widget::column()
+                    .width(Length::Fill)
+                    .push(widget::text::text(fl!("title-countdown")))
+                    .push(
+                        widget::toggler(self.title_countdown)
+                            .on_toggle(SettingsMessage::TitleCountdownToggle),
+                    ),
+            )
+            .into()
+    }
+
+    pub fn shortcuts_view<'a>(&'a self) -> Element<'a, SettingsMessage> {
+        let mut section = widget::settings::section().title(fl!("shortcuts"));
+        let key_binds = shortcuts::key_binds(&self.shortcuts);
+
+        for shortcut in Shortcut::all() {
+            let binding = self
+                .shortcuts
+                .get(&shortcut)
+                .cloned()
+                .unwrap_or_else(|| shortcut.default_binding());
+            let key_bind = shortcuts::parse(&binding);
+            let valid = binding.trim().is_empty() || key_bind.is_some();
+            // Only the first shortcut gets a binding used twice
+            let taken =
+                key_bind.is_some_and(|key_bind| key_binds.get(&key_bind) != Some(&shortcut));
+
+            section = section.add(
+                widget::row()
+                    .push(
+                        widget::column()
+                            .push(widget::text::text(shortcut_label(shortcut)))
+                            .width(Length::Fill),
+                    )
+                    .push(
+                        widget::column()
+                            .push(
+                                widget::text_input(fl!("shortcut-placeholder"), binding).on_input(
+                                    move |binding| {
+                                        SettingsMessage::ShortcutChanged(shortcut, binding)
+                                    },
+                                ),
+                            )
+                            .push_maybe(
+                                (!valid).then(|| widget::text::caption(fl!("shortcut-invalid"))),
+                            )
+                            .push_maybe(taken.then(|| widget::text::caption(fl!("shortcut-taken"))))
+                            .width(Length::Fixed(180.0)),
+                    )
+                    .align_y(iced::Alignment::Center),
+            );
+        }
+
+        section.into()
+    }
+
+    pub fn hooks_view<'a>(&'a self) -> Element<'a, SettingsMessage> {
+        let mut section = widget::settings::section().title(fl!("hooks"));
+
+        for &kind in TimerEventKind::all() {
+            section = section.add(
+                widget::column()
+                    .push(widget::text::text(hook_label(kind)))
+                    .push(
+                        widget::text_input(fl!("hook-placeholder"), self.hooks.get(kind))
+                            .on_input(move |program| SettingsMessage::HookChanged(kind, program))
+                            .width(Length::Fill),
+                    ),
+            );
+        }
+
+        section
+            .add(
+                widget::row()
+                    .push(
+                        widget::column()
+                            .push(widget::text::text(fl!("hook-timeout")))
+                            .width(Length::Fill),
+                    )
+                    .push(
+                        widget::column()
+                            .push(widget::spin_button(
+                                self.hook_timeout_str.clone(),
+                                self.hook_timeout,
+                                1,
+                                1,
+                                600,
+                                SettingsMessage::HookTimeoutChanged,
+                            ))
+                            .width(Length::Fill)
+                            .align_x(iced::Alignment::End),
+                    ),
+            )
+            .into()
+    }
+
+    pub fn webhooks_view<'a>(&'a self) -> Element<'a, SettingsMessage> {
+        let mut section = widget::settings::section().title(fl!("webhooks"));
+
+        for (index, url) in self.webhook_urls.iter().enumerate() {
+            section = section.add(
+                widget::row()
+                    .push(
+                        widget::text_input(fl!("webhook-placeholder"), url)
+                            .on_input(move |url| SettingsMessage::WebhookUrlChanged(index, url))
+                            .width(Length::Fill),
+                    )
+                    .push(
+                        widget::button::icon(widget::icon::from_name("edit-delete-symbolic"))
+                            .on_press(SettingsMessage::RemoveWebhook(index)),
+                    )
+                    .align_y(iced::Alignment::Center)
+                    .spacing(10),
+            );
+        }
+
+        section
+            .add(widget::button::standard(fl!("add-webhook")).on_press(SettingsMessage::AddWebhook))
+            .add(
+                widget::row()
+                    .push(
+                        widget::column()
+                            .push(widget::text::text(fl!("webhook-timeout")))
+                            .width(Length::Fill),
+                    )
+                    .push(
+                        widget::column()
+                            .push(widget::spin_button(
+                                self.webhook_timeout_str.clone(),
+                                self.webhook_timeout,
+                                1,
+                                1,
+                                60,
+                                SettingsMessage::WebhookTimeoutChanged,
+                            ))
+                            .width(Length::Fill)
+                            .align_x(iced::Alignment::End),
+                    ),
+            )
+            .add(
+                widget::row()
+                    .push(
+                        widget::column()
+                            .push(widget::text::text(fl!("webhook-retries")))
+                            .width(Length::Fill),
+                    )
+                    .push(
+                        widget::column()
+                            .push(widget::spin_button(
+                                self.webhook_retries_str.clone(),
+                                self.webhook_retries,
+                                1,
+                                0,
+                                10,
+                                SettingsMessage::WebhookRetriesChanged,
+                            ))
+                            .width(Length::Fill)
+                            .align_x(iced::Alignment::End),
+                    ),
+            )
+            .into()
+    }
+
     pub fn update(&mut self, message: SettingsMessage) -> Task<crate::app::Message> {
         let mut commands = Vec::new();
         match message {
@@ -221,10 +843,292 @@ impl Settings {
                 let mut config = Config::load();
                 let _ = config.1.set_notifications_active(&config.0.unwrap(), value);
             }
+            SettingsMessage::BreakOverlayToggle(value) => {
+                self.break_overlay = value;
+                let mut config = Config::load();
+                let _ = config.1.set_break_overlay(&config.0.unwrap(), value);
+                return Task::none();
+            }
+            SettingsMessage::BreakPostponeMinutesChanged(value) => {
+                self.break_postpone_minutes = value;
+                self.break_postpone_minutes_str = format!("{} {}", value, fl!("minutes"));
+                let mut config = Config::load();
+                let _ = config
+                    .1
+                    .set_break_postpone_minutes(&config.0.unwrap(), value);
+                return Task::perform(async {}, |_| {
+                    Message::Pomodoro(super::pomodoro::PomodoroMessage::UpdateBreakPostpone)
+                });
+            }
+            SettingsMessage::BreakPostponeLimitChanged(value) => {
+                self.break_postpone_limit = value;
+                self.break_postpone_limit_str = value.to_string();
+                let mut config = Config::load();
+                let _ = config.1.set_break_postpone_limit(&config.0.unwrap(), value);
+                return Task::perform(async {}, |_| {
+                    Message::Pomodoro(super::pomodoro::PomodoroMessage::UpdateBreakPostpone)
+                });
+            }
+            SettingsMessage::FlowtimeBreakRatioChanged(value) => {
+                self.flowtime_break_ratio = value;
+                self.flowtime_break_ratio_str = format!("1/{}", value);
+                let mut config = Config::load();
+                let _ = config.1.set_flowtime_break_ratio(&config.0.unwrap(), value);
+                return Task::perform(async {}, |_| {
+                    Message::Pomodoro(super::pomodoro::PomodoroMessage::UpdateFlowtimeBreakRatio)
+                });
+            }
+            SettingsMessage::DailyGoalChanged(value) => {
+                self.daily_goal = value;
+                self.daily_goal_str = value.to_string();
+                let mut config = Config::load();
+                let _ = config.1.set_daily_goal(&config.0.unwrap(), value);
+                return Task::perform(async {}, |_| {
+                    Message::Pomodoro(super::pomodoro::PomodoroMessage::UpdateGoal)
+                });
+            }
+            SettingsMessage::DailyGoalUnitSelected(index) => {
+                if let Some(&unit) = GoalUnit::all().get(index) {
+                    self.daily_goal_unit = unit;
+                    let mut config = Config::load();
+                    let _ = config.1.set_daily_goal_unit(&config.0.unwrap(), unit);
+                }
+                return Task::perform(async {}, |_| {
+                    Message::Pomodoro(super::pomodoro::PomodoroMessage::UpdateGoal)
+                });
+            }
+            SettingsMessage::DailySummaryToggle(value) => {
+                self.daily_summary = value;
+                let mut config = Config::load();
+                let _ = config.1.set_daily_summary(&config.0.unwrap(), value);
+                return Task::perform(async {}, |_| {
+                    Message::Pomodoro(super::pomodoro::PomodoroMessage::UpdateDailySummary)
+                });
+            }
+            SettingsMessage::DailySummaryHourChanged(hour) => {
+                self.daily_summary_time.0 = hour;
+                self.daily_summary_hour_str = format!("{:02}", hour);
+                self.save_daily_summary_time();
+                return Task::perform(async {}, |_| {
+                    Message::Pomodoro(super::pomodoro::PomodoroMessage::UpdateDailySummary)
+                });
+            }
+            SettingsMessage::DailySummaryMinuteChanged(minute) => {
+                self.daily_summary_time.1 = minute;
+                self.daily_summary_minute_str = format!("{:02}", minute);
+                self.save_daily_summary_time();
+                return Task::perform(async {}, |_| {
+                    Message::Pomodoro(super::pomodoro::PomodoroMessage::UpdateDailySummary)
+                });
+            }
+            SettingsMessage::CompactHideHeaderToggle(value) => {
+                self.compact_hide_header = value;
+                let mut config = Config::load();
+                let _ = config.1.set_compact_hide_header(&config.0.unwrap(), value);
+                return Task::none();
+            }
+            SettingsMessage::TitleCountdownToggle(value) => {
+                self.title_countdown = value;
+                let mut config = Config::load();
+                let _ = config.1.set_title_countdown(&config.0.unwrap(), value);
+                return Task::none();
+            }
+            SettingsMessage::ShortcutChanged(shortcut, binding) => {
+                if binding == shortcut.default_binding() {
+                    self.shortcuts.remove(&shortcut);
+                } else {
+                    self.shortcuts.insert(shortcut, binding);
+                }
+                let mut config = Config::load();
+                let _ = config
+                    .1
+                    .set_shortcuts(&config.0.unwrap(), self.shortcuts.clone());
+                return Task::none();
+            }
+            // Hooks don't affect the running timer, so it is left alone.
+            SettingsMessage::HookChanged(kind, program) => {
+                self.hooks.set(kind, program);
+                let mut config = Config::load();
+                let _ = config.1.set_hooks(&config.0.unwrap(), self.hooks.clone());
+                return Task::none();
+            }
+            SettingsMessage::HookTimeoutChanged(value) => {
+                self.hook_timeout = value;
+                self.hook_timeout_str = format!("{} {}", value, fl!("seconds"));
+                let mut config = Config::load();
+                let _ = config.1.set_hook_timeout(&config.0.unwrap(), value);
+                return Task::none();
+            }
+            SettingsMessage::DoNotDisturbToggle(value) => {
+                self.do_not_disturb = value;
+                let mut config = Config::load();
+                let _ = config.1.set_do_not_disturb(&config.0.unwrap(), value);
+                return Task::none();
+            }
+            SettingsMessage::InhibitSleepToggle(value) => {
+                self.inhibit_sleep = value;
+                let mut config = Config::load();
+                let _ = config.1.set_inhibit_sleep(&config.0.unwrap(), value);
+                return Task::none();
+            }
+            SettingsMessage::AutoPauseOnLockToggle(value) => {
+                self.auto_pause_on_lock = value;
+                let mut config = Config::load();
+                let _ = config.1.set_auto_pause_on_lock(&config.0.unwrap(), value);
+                return Task::none();
+            }
+            SettingsMessage::AutoPauseOnIdleToggle(value) => {
+                self.auto_pause_on_idle = value;
+                let mut config = Config::load();
+                let _ = config.1.set_auto_pause_on_idle(&config.0.unwrap(), value);
+                return Task::none();
+            }
+            SettingsMessage::ReflectionPromptToggle(value) => {
+                self.reflection_prompt = value;
+                let mut config = Config::load();
+                let _ = config.1.set_reflection_prompt(&config.0.unwrap(), value);
+                return Task::none();
+            }
+            SettingsMessage::SoundsToggle(value) => {
+                self.sounds_active = value;
+                let mut config = Config::load();
+                let _ = config.1.set_sounds_active(&config.0.unwrap(), value);
+                return Task::none();
+            }
+            SettingsMessage::SoundFileChanged(sound, file) => {
+                let mut config = Config::load();
+                let _ = match sound {
+                    Sound::FocusEnd => {
+                        self.focus_end_sound = file;
+                        config
+                            .1
+                            .set_focus_end_sound(&config.0.unwrap(), self.focus_end_sound.clone())
+                    }
+                    Sound::BreakEnd => {
+                        self.break_end_sound = file;
+                        config
+                            .1
+                            .set_break_end_sound(&config.0.unwrap(), self.break_end_sound.clone())
+                    }
+                };
+                return Task::none();
+            }
+            SettingsMessage::SoundVolumeChanged(value) => {
+                self.sound_volume = value;
+                let mut config = Config::load();
+                let _ = config
+                    .1
+                    .set_sound_volume(&config.0.unwrap(), self.sound_volume as u32);
+                return Task::none();
+            }
+            SettingsMessage::PreviewSound(sound) => {
+                let file = match sound {
+                    Sound::FocusEnd => self.focus_end_sound.clone(),
+                    Sound::BreakEnd => self.break_end_sound.clone(),
+                };
+                let volume = self.sound_volume as u32;
+                return Task::perform(async {}, move |_| {
+                    Message::PreviewSound(sound, file, volume)
+                });
+            }
+            SettingsMessage::AmbienceSelected(index) => {
+                if let Some(&ambience) = Ambience::all().get(index) {
+                    self.ambience = ambience;
+                    let mut config = Config::load();
+                    let _ = config.1.set_ambience(&config.0.unwrap(), ambience);
+                }
+                return Task::none();
+            }
+            SettingsMessage::AmbienceVolumeChanged(value) => {
+                self.ambience_volume = value;
+                let mut config = Config::load();
+                let _ = config
+                    .1
+                    .set_ambience_volume(&config.0.unwrap(), self.ambience_volume as u32);
+                return Task::none();
+            }
+            SettingsMessage::WebhookUrlChanged(index, url) => {
+                if let Some(webhook_url) = self.webhook_urls.get_mut(index) {
+                    *webhook_url = url;
+                }
+                self.save_webhook_urls();
+                return Task::none();
+            }
+            SettingsMessage::AddWebhook => {
+                self.webhook_urls.push(String::new());
+                self.save_webhook_urls();
+                return Task::none();
+            }
+            SettingsMessage::RemoveWebhook(index) => {
+                if index < self.webhook_urls.len() {
+                    self.webhook_urls.remove(index);
+                }
+                self.save_webhook_urls();
+                return Task::none();
+            }
+            SettingsMessage::WebhookTimeoutChanged(value) => {
+                self.webhook_timeout = value;
+                self.webhook_timeout_str = format!("{} {}", value, fl!("seconds"));
+                let mut config = Config::load();
+                let _ = config.1.set_webhook_timeout(&config.0.unwrap(), value);
+                return Task::none();
+            }
+            SettingsMessage::WebhookRetriesChanged(value) => {
+                self.webhook_retries = value;
+                self.webhook_retries_str = value.to_string();
+                let mut config = Config::load();
+                let _ = config.1.set_webhook_retries(&config.0.unwrap(), value);
+                return Task::none();
+            }
         }
         commands.push(Task::perform(async {}, |_| {
             Message::Pomodoro(super::pomodoro::PomodoroMessage::UpdateConfig)
         }));
         Task::batch(commands)
     }
+
+    fn save_daily_summary_time(&self) {
+        let mut config = Config::load();
+        let _ = config
+            .1
+            .set_daily_summary_time(&config.0.unwrap(), self.daily_summary_time);
+    }
+
+    fn save_webhook_urls(&self) {
+        let mut config = Config::load();
+        let _ = config
+            .1
+            .set_webhook_urls(&config.0.unwrap(), self.webhook_urls.clone());
+    }
+}
+
+fn ambience_label(ambience: &Ambience) -> String {
+    match ambience {
+        Ambience::Off => fl!("ambience-off"),
+        Ambience::Tick => fl!("ambience-tick"),
+        Ambience::WhiteNoise => fl!("ambience-white-noise"),
+        Ambience::BrownNoise => fl!("ambience-brown-noise"),
+    }
+}
+
+fn shortcut_label(shortcut: Shortcut) -> String {
+    match shortcut {
+        Shortcut::StartPause => fl!("start-pause"),
+        Shortcut::Skip => fl!("skip"),
+        Shortcut::Reset => fl!("reset"),
+        Shortcut::Settings => fl!("settings"),
+        Shortcut::CompactMode => fl!("compact-mode"),
+        Shortcut::Page(page) => fl!("shortcut-page", page = page.title()),
+    }
+}
+
+fn hook_label(kind: TimerEventKind) -> String {
+    match kind {
+        TimerEventKind::FocusStart => fl!("hook-focus-start"),
+        TimerEventKind::FocusEnd => fl!("hook-focus-end"),
+        TimerEventKind::BreakStart => fl!("hook-break-start"),
+        TimerEventKind::BreakEnd => fl!("hook-break-end"),
+        TimerEventKind::Paused => fl!("hook-paused"),
+        TimerEventKind::Reset => fl!("hook-reset"),
+    }
 }
@@ -0,0 +1,218 @@
+use chrono::Days;
+use cosmic::iced::Alignment;
+use cosmic::{
+    iced::{Length, Padding},
+    widget, Element, Task,
+};
+
+use crate::core::history::{self, DaySummary, Entry, InterruptionKind};
+use crate::core::timer::format_duration;
+use crate::{app::Message, fl};
+
+#[derive(Debug, Clone)]
+pub enum StatisticsMessage {
+    PeriodSelected(usize),
+}
+
+/// Days the statistics cover, up to today.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum Period {
+    #[default]
+    Today,
+    Week,
+    Month,
+}
+
+impl Period {
+    pub fn all() -> &'static [Self] {
+        &[Self::Today, Self::Week, Self::Month]
+    }
+
+    pub fn label(&self) -> String {
+        match self {
+            Self::Today => fl!("today"),
+            Self::Week => fl!("statistics-week"),
+            Self::Month => fl!("statistics-month"),
+        }
+    }
+
+    fn days(&self) -> u64 {
+        match self {
+            Self::Today => 1,
+            Self::Week => 7,
+            Self::Month => 30,
+        }
+    }
+}
+
+pub struct Statistics {
+    period: Period,
+    period_names: Vec<String>,
+}
+
+impl Default for Statistics {
+    fn default() -> Self {
+        Self {
+            period: Period::default(),
+            period_names: Period::all().iter().map(Period::label).collect(),
+        }
+    }
+}
+
+impl Statistics {
+    pub fn view<'a>(&'a self, entries: &[Entry]) -> Element<'a, StatisticsMessage> {
+        let from = history::today()
+            .checked_sub_days(Days::new(self.period.days() - 1))
+            .unwrap_or_default();
+        let (total, days) = history::summarize_since(entries, from);
+
+        let col = widget::column()
+            .push(widget::text::title1(fl!("statistics")))
+            .push(widget::Space::with_height(10))
+            .push(widget::dropdown(
+                &self.period_names,
+                Period::all()
+                    .iter()
+                    .position(|period| *period == self.period),
+                StatisticsMessage::PeriodSelected,
+            ))
+            .push(card(fl!("statistics-focus"), focus_view(&total)))
+            .push(card(
+                fl!("statistics-interruptions"),
+                interruptions_view(&total),
+            ))
+            .push_maybe((self.period != Period::Today).then(|| {
+                let mut list = widget::column();
+                if days.is_empty() {
+                    list = list.push(widget::text::text(fl!("no-elements")));
+                }
+                // Most recent day first
+                for (date, summary) in days.iter().rev() {
+                    list = list.push(line(
+                        history::format_day(*date),
+                        format!(
+                            "{} · {}",
+                            summary.focus_blocks,
+                            format_duration(summary.focus_seconds)
+                        ),
+                    ));
+                }
+                card(fl!("statistics-days"), list.into())
+            }))
+            .push(card(fl!("statistics-tasks"), tasks_view(&total)))
+            .align_x(Alignment::Center);
+
+        widget::scrollable(
+            widget::container(col)
+                .width(Length::Fill)
+                .height(Length::Shrink)
+                .align_x(Alignment::Center),
+        )
+        .into()
+    }
+
+    pub fn update(&mut self, message: StatisticsMessage) -> Task<Message> {
+        match message {
+            StatisticsMessage::PeriodSelected(index) => {
+                if let Some(&period) = Period::all().get(index) {
+                    self.period = period;
+                }
+            }
+        }
+        Task::none()
+    }
+}
+
+fn focus_view<'a>(total: &DaySummary) -> Element<'a, StatisticsMessage> {
+    widget::column()
+        .push(line(
+            fl!("statistics-focus-blocks"),
+            total.focus_blocks.to_string(),
+        ))
+        .push(line(
+            fl!("statistics-focus-time"),
+            format_duration(total.focus_seconds),
+        ))
+        .push(line(
+            fl!("statistics-break-time"),
+            format_duration(total.break_seconds),
+        ))
+        .push(line(
+            fl!("statistics-rating"),
+            total
+                .average_rating()
+                .map(|rating| format!("{rating:.1} / 5"))
+                .unwrap_or_else(|| "–".into()),
+        ))
+        .into()
+}
+
+fn interruptions_view<'a>(total: &DaySummary) -> Element<'a, StatisticsMessage> {
+    let per_block = if total.focus_blocks > 0 {
+        format!(
+            "{:.1}",
+            total.interruptions() as f32 / total.focus_blocks as f32
+        )
+    } else {
+        "–".into()
+    };
+    widget::column()
+        .push(line(
+            InterruptionKind::Internal.label(),
+            total.internal_interruptions.to_string(),
+        ))
+        .push(line(
+            InterruptionKind::External.label(),
+            total.external_interruptions.to_string(),
+        ))
+        .push(line(fl!("statistics-interruptions-per-block"), per_block))
+        .into()
+}
+
+fn tasks_view<'a>(total: &DaySummary) -> Element<'a, StatisticsMessage> {
+    if total.tasks.is_empty() {
+        return widget::text::text(fl!("no-elements")).into();
+    }
+    let mut list = widget::column();
+    for task in &total.tasks {
+        list = list.push(widget::text::text(task.clone()));
+    }
+    list.into()
+}
+
+/// A label with its value on the right.
+fn line<'a>(label: String, value: String) -> Element<'a, StatisticsMessage> {
+    widget::row()
+        .push(widget::text::text(label).width(Length::Fill))
+        .push(widget::text::text(value))
+        .padding(Padding::from([5, 0]))
+        .align_y(Alignment::Center)
+        .into()
+}
+
+fn card<'a>(
+    title: String,
+    content: Element<'a, StatisticsMessage>,
+) -> Element<'a, StatisticsMessage> {
+    widget::column()
+        .push(widget::Space::with_height(20))
+        .push(
+            widget::column()
+                .width(Length::Fixed(350.))
+                .push(
+                    widget::container(widget::text::text(title)).padding(Padding {
+                        top: 0.,
+                        right: 0.,
+                        bottom: 0.,
+                        left: 10.,
+                    }),
+                )
+                .push(widget::Space::with_height(5))
+                .push(
+                    widget::container(widget::column().push(content).padding(Padding::from(10)))
+                        .class(cosmic::theme::Container::Card)
+                        .width(Length::Fixed(350.)),
+                ),
+        )
+        .into()
+}
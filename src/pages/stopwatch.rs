@@ -0,0 +1,343 @@
+use std::time::{Duration, SystemTime};
+
+use cosmic::iced::{clipboard, Alignment, Color};
+use cosmic::{
+    iced::{Length, Padding},
+    theme,
+    widget::{self, icon},
+    Element, Task,
+};
+
+use crate::core::timer::format_duration;
+use crate::{app::Message, fl};
+
+#[derive(Debug, Clone)]
+pub enum StopwatchMessage {
+    /// Redraws the running stopwatch, time itself is read from the clock.
+    Tick,
+    Start,
+    Stop,
+    Reset,
+    Lap,
+    Copy,
+}
+
+#[derive(Default)]
+pub struct Stopwatch {
+    /// Time counted before the last start.
+    elapsed: Duration,
+    /// When the stopwatch was last started, while it runs. The wall clock keeps
+    /// counting while the computer is suspended.
+    started_at: Option<SystemTime>,
+    /// Split time at the end of each lap.
+    splits: Vec<Duration>,
+}
+
+impl Stopwatch {
+    pub fn view<'a>(&'a self) -> Element<'a, StopwatchMessage> {
+        let elapsed = self.elapsed();
+
+        let mut controls = widget::row().spacing(10);
+        controls = if self.is_running() {
+            controls
+                .push(
+                    widget::button::icon(icon::from_name("media-playback-pause-symbolic"))
+                        .extra_large()
+                        .on_press(StopwatchMessage::Stop),
+                )
+                .push(
+                    widget::button::icon(icon::from_name("flag-symbolic"))
+                        .extra_large()
+                        .tooltip(fl!("stopwatch-lap"))
+                        .on_press(StopwatchMessage::Lap),
+                )
+        } else {
+            controls
+                .push(
+                    widget::button::icon(icon::from_name("media-playback-start-symbolic"))
+                        .extra_large()
+                        .on_press(StopwatchMessage::Start),
+                )
+                .push(
+                    widget::button::icon(icon::from_name("media-playback-stop-symbolic"))
+                        .extra_large()
+                        .tooltip(fl!("reset"))
+                        .on_press_maybe((!elapsed.is_zero()).then_some(StopwatchMessage::Reset)),
+                )
+        };
+
+        let col = widget::column()
+            .push(widget::text::title1(fl!("stopwatch")))
+            .push(widget::Space::with_height(10))
+            .push(widget::text::title1(format_elapsed(elapsed)))
+            .push(widget::Space::with_height(10))
+            .push(controls)
+            .push(self.laps_view())
+            .align_x(Alignment::Center);
+
+        widget::scrollable(
+            widget::container(col)
+                .width(Length::Fill)
+                .height(Length::Shrink)
+                .align_x(Alignment::Center),
+        )
+        .into()
+    }
+
+    fn laps_view<'a>(&'a self) -> Element<'a, StopwatchMessage> {
+        let laps = self.laps();
+        // Highlighting a single lap would be meaningless
+        let (best, worst) = if laps.len() > 1 {
+            (laps.iter().min().copied(), laps.iter().max().copied())
+        } else {
+            (None, None)
+        };
+        let theme = theme::active();
+        let cosmic = theme.cosmic();
+
+        let mut inner_col = widget::column();
+        if laps.is_empty() {
+            inner_col = inner_col.push(widget::text::text(fl!("no-elements")));
+        }
+        // Most recent lap first
+        for (index, (lap, split)) in laps.iter().zip(&self.splits).enumerate().rev() {
+            let color: Option<Color> = if Some(*lap) == best {
+                Some(cosmic.success_color().into())
+            } else if Some(*lap) == worst {
+                Some(cosmic.destructive_color().into())
+            } else {
+                None
+            };
+            let lap_text = widget::text::text(format_elapsed(*lap));
+            let lap_text = match color {
+                Some(color) => lap_text.class(theme::Text::Color(color)),
+                None => lap_text,
+            };
+
+            inner_col = inner_col.push(
+                widget::row()
+                    .push(
+                        widget::column()
+                            .push(widget::text::text(fl!(
+                                "stopwatch-lap-number",
+                                number = index + 1
+                            )))
+                            .width(Length::Fill),
+                    )
+                    .push(widget::column().push(lap_text).width(Length::Fill))
+                    .push(
+                        widget::column()
+                            .push(widget::text::text(format_elapsed(*split)))
+                            .width(Length::Fill)
+                            .align_x(Alignment::End),
+                    ),
+            );
+            inner_col = inner_col.push(widget::Space::with_height(5));
+            inner_col = inner_col.push(widget::divider::horizontal::default());
+            inner_col = inner_col.push(widget::Space::with_height(5));
+        }
+
+        widget::column()
+            .push(widget::Space::with_height(20))
+            .push(
+                widget::column()
+                    .width(Length::Fixed(350.))
+                    .push(
+                        widget::row()
+                            .push(
+                                widget::container(widget::text::text(fl!("stopwatch-laps")))
+                                    .padding(Padding {
+                                        top: 0.,
+                                        right: 0.,
+                                        bottom: 0.,
+                                        left: 10.,
+                                    })
+                                    .width(Length::Fill),
+                            )
+                            .push(
+                                widget::button::icon(icon::from_name("edit-copy-symbolic"))
+                                    .tooltip(fl!("copy"))
+                                    .on_press_maybe(
+                                        (!laps.is_empty()).then_some(StopwatchMessage::Copy),
+                                    ),
+                            )
+                            .align_y(Alignment::Center),
+                    )
+                    .push(widget::Space::with_height(5))
+                    .push(
+                        widget::container(
+                            widget::column().push(inner_col).padding(Padding::from(10)),
+                        )
+                        .class(cosmic::theme::Container::Card)
+                        .width(Length::Fixed(350.)),
+                    ),
+            )
+            .into()
+    }
+
+    pub fn update(&mut self, message: StopwatchMessage) -> Task<Message> {
+        match message {
+            StopwatchMessage::Tick => {}
+            StopwatchMessage::Start => self.start(SystemTime::now()),
+            StopwatchMessage::Stop => self.stop(SystemTime::now()),
+            StopwatchMessage::Reset => {
+                *self = Self::default();
+            }
+            StopwatchMessage::Lap => self.lap(SystemTime::now()),
+            StopwatchMessage::Copy => {
+                return clipboard::write(self.laps_text());
+            }
+        }
+        Task::none()
+    }
+
+    pub fn is_running(&self) -> bool {
+        self.started_at.is_some()
+    }
+
+    fn start(&mut self, now: SystemTime) {
+        if self.started_at.is_none() {
+            self.started_at = Some(now);
+        }
+    }
+
+    fn stop(&mut self, now: SystemTime) {
+        self.elapsed = self.elapsed_at(now);
+        self.started_at = None;
+    }
+
+    fn lap(&mut self, now: SystemTime) {
+        if self.is_running() {
+            self.splits.push(self.elapsed_at(now));
+        }
+    }
+
+    fn elapsed(&self) -> Duration {
+        self.elapsed_at(SystemTime::now())
+    }
+
+    /// Time counted up to `now`. A clock set back doesn't count backwards.
+    fn elapsed_at(&self, now: SystemTime) -> Duration {
+        self.elapsed
+            + self
+                .started_at
+                .and_then(|started_at| now.duration_since(started_at).ok())
+                .unwrap_or_default()
+    }
+
+    /// Length of each lap, the difference between consecutive splits.
+    fn laps(&self) -> Vec<Duration> {
+        let mut previous = Duration::ZERO;
+        self.splits
+            .iter()
+            .map(|&split| {
+                let lap = split.saturating_sub(previous);
+                previous = split;
+                lap
+            })
+            .collect()
+    }
+
+    /// Laps as tab separated lines, which paste well into spreadsheets.
+    fn laps_text(&self) -> String {
+        self.laps()
+            .iter()
+            .zip(&self.splits)
+            .enumerate()
+            .map(|(index, (lap, split))| {
+                format!(
+                    "{}\t{}\t{}\n",
+                    index + 1,
+                    format_elapsed(*lap),
+                    format_elapsed(*split)
+                )
+            })
+            .collect()
+    }
+}
+
+/// Formats a duration as HH:MM:SS.mmm.
+fn format_elapsed(duration: Duration) -> String {
+    format!(
+        "{}.{:03}",
+        format_duration(duration.as_secs() as u32),
+        duration.subsec_millis()
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn at(millis: u64) -> SystemTime {
+        SystemTime::UNIX_EPOCH + Duration::from_millis(millis)
+    }
+
+    #[test]
+    fn counts_only_while_running() {
+        let mut stopwatch = Stopwatch::default();
+        stopwatch.start(at(1_000));
+        assert_eq!(
+            stopwatch.elapsed_at(at(3_500)),
+            Duration::from_millis(2_500)
+        );
+
+        stopwatch.stop(at(4_000));
+        assert!(!stopwatch.is_running());
+        assert_eq!(stopwatch.elapsed_at(at(60_000)), Duration::from_secs(3));
+
+        stopwatch.start(at(10_000));
+        // Starting again while running keeps the first start
+        stopwatch.start(at(11_000));
+        assert_eq!(stopwatch.elapsed_at(at(12_000)), Duration::from_secs(5));
+    }
+
+    #[test]
+    fn laps_are_the_time_between_splits() {
+        let mut stopwatch = Stopwatch::default();
+        stopwatch.lap(at(0));
+        assert!(stopwatch.splits.is_empty());
+
+        stopwatch.start(at(0));
+        stopwatch.lap(at(1_500));
+        stopwatch.stop(at(2_000));
+        stopwatch.start(at(5_000));
+        stopwatch.lap(at(6_000));
+
+        assert_eq!(
+            stopwatch.splits,
+            [Duration::from_millis(1_500), Duration::from_millis(3_000)]
+        );
+        assert_eq!(
+            stopwatch.laps(),
+            [Duration::from_millis(1_500), Duration::from_millis(1_500)]
+        );
+        assert_eq!(
+            stopwatch.laps_text(),
+            "1\t00:00:01.500\t00:00:01.500\n2\t00:00:01.500\t00:00:03.000\n"
+        );
+    }
+
+    #[test]
+    fn a_clock_set_back_does_not_count_backwards() {
+        let mut stopwatch = Stopwatch::default();
+        stopwatch.start(at(10_000));
+        stopwatch.lap(at(12_000));
+        stopwatch.lap(at(5_000));
+
+        assert_eq!(stopwatch.elapsed_at(at(5_000)), Duration::ZERO);
+        assert_eq!(stopwatch.laps(), [Duration::from_secs(2), Duration::ZERO]);
+    }
+
+    #[test]
+    fn reset_clears_everything() {
+        let mut stopwatch = Stopwatch::default();
+        stopwatch.start(at(0));
+        stopwatch.lap(at(1_000));
+        let _ = stopwatch.update(StopwatchMessage::Reset);
+
+        assert!(!stopwatch.is_running());
+        assert!(stopwatch.splits.is_empty());
+        assert_eq!(stopwatch.elapsed(), Duration::ZERO);
+    }
+}
@@ -0,0 +1,241 @@
+use cosmic::iced::Alignment;
+use cosmic::{
+    iced::{Length, Padding},
+    widget::{self, icon},
+    Element, Task,
+};
+use notify_rust::Notification;
+
+use crate::core::countdowns::{self, Countdown};
+use crate::core::status::Status;
+use crate::core::timer::format_duration;
+use crate::{app::Message, fl};
+
+#[derive(Debug, Clone)]
+pub enum TimersMessage {
+    Tick,
+    NameChanged(String),
+    MinutesChanged(u32),
+    SoundChanged(String),
+    Add,
+    Start(usize),
+    Pause(usize),
+    Reset(usize),
+    Remove(usize),
+}
+
+pub struct Timers {
+    countdowns: Vec<Countdown>,
+    new_name: String,
+    new_minutes: u32,
+    new_minutes_str: String,
+    new_sound: String,
+}
+
+impl Default for Timers {
+    fn default() -> Self {
+        Self {
+            countdowns: countdowns::load(),
+            new_name: String::new(),
+            new_minutes: 10,
+            new_minutes_str: format!("10 {}", fl!("minutes")),
+            new_sound: String::new(),
+        }
+    }
+}
+
+impl Timers {
+    pub fn view<'a>(&'a self) -> Element<'a, TimersMessage> {
+        let col = widget::column()
+            .push(widget::text::title1(fl!("timers")))
+            .push(widget::Space::with_height(10))
+            .push(self.new_view())
+            .push(self.list_view())
+            .align_x(Alignment::Center);
+
+        widget::container(col)
+            .width(Length::Fill)
+            .height(Length::Fill)
+            .align_x(Alignment::Center)
+            .align_y(Alignment::Center)
+            .into()
+    }
+
+    fn new_view<'a>(&'a self) -> Element<'a, TimersMessage> {
+        widget::container(
+            widget::column()
+                .push(
+                    widget::text_input(fl!("timer-name"), &self.new_name)
+                        .on_input(TimersMessage::NameChanged),
+                )
+                .push(widget::Space::with_height(5))
+                .push(
+                    widget::text_input(fl!("sound-default"), &self.new_sound)
+                        .on_input(TimersMessage::SoundChanged),
+                )
+                .push(widget::Space::with_height(5))
+                .push(
+                    widget::row()
+                        .push(widget::spin_button(
+                            self.new_minutes_str.clone(),
+                            self.new_minutes,
+                            1,
+                            1,
+                            1440,
+                            TimersMessage::MinutesChanged,
+                        ))
+                        .push(widget::horizontal_space())
+                        .push(
+                            widget::button::standard(fl!("add-timer"))
+                                .on_press_maybe(self.can_add().then_some(TimersMessage::Add)),
+                        )
+                        .align_y(Alignment::Center),
+                )
+                .padding(Padding::from(10)),
+        )
+        .class(cosmic::theme::Container::Card)
+        .width(Length::Fixed(350.))
+        .into()
+    }
+
+    fn list_view<'a>(&'a self) -> Element<'a, TimersMessage> {
+        let now = Status::now();
+
+        let mut inner_col = widget::column();
+        if self.countdowns.is_empty() {
+            inner_col = inner_col.push(widget::text::text(fl!("no-elements")));
+        }
+        for (index, countdown) in self.countdowns.iter().enumerate() {
+            let toggle = if countdown.is_running() {
+                widget::button::icon(icon::from_name("media-playback-pause-symbolic"))
+                    .on_press(TimersMessage::Pause(index))
+            } else {
+                widget::button::icon(icon::from_name("media-playback-start-symbolic"))
+                    .on_press(TimersMessage::Start(index))
+            };
+
+            inner_col = inner_col.push(
+                widget::row()
+                    .push(
+                        widget::column()
+                            .push(widget::text::text(&countdown.name))
+                            .push(widget::text::caption(format_duration(countdown.duration)))
+                            .width(Length::Fill),
+                    )
+                    .push(widget::text::title4(format_duration(
+                        countdown.remaining_at(now),
+                    )))
+                    .push(toggle)
+                    .push(
+                        widget::button::icon(icon::from_name("view-refresh-symbolic"))
+                            .tooltip(fl!("reset"))
+                            .on_press(TimersMessage::Reset(index)),
+                    )
+                    .push(
+                        widget::button::icon(icon::from_name("user-trash-symbolic"))
+                            .on_press(TimersMessage::Remove(index)),
+                    )
+                    .spacing(5)
+                    .align_y(Alignment::Center),
+            );
+            inner_col = inner_col.push(widget::Space::with_height(5));
+            inner_col = inner_col.push(widget::divider::horizontal::default());
+            inner_col = inner_col.push(widget::Space::with_height(5));
+        }
+
+        widget::column()
+            .push(widget::Space::with_height(20))
+            .push(
+                widget::container(widget::column().push(inner_col).padding(Padding::from(10)))
+                    .class(cosmic::theme::Container::Card)
+                    .width(Length::Fixed(350.)),
+            )
+            .into()
+    }
+
+    pub fn update(&mut self, message: TimersMessage) -> Task<Message> {
+        let mut commands = vec![];
+        let now = Status::now();
+        match message {
+            TimersMessage::Tick => {
+                let mut changed = false;
+                for countdown in &mut self.countdowns {
+                    if countdown.has_finished(now) {
+                        log::info!("timer '{}' finished", countdown.name);
+                        let _ = Notification::new()
+                            .summary(&countdown.name)
+                            .body(&fl!("timer-finished"))
+                            .appname("Chronos")
+                            .show();
+                        let sound = countdown.sound.clone();
+                        commands.push(Task::perform(async {}, move |_| {
+                            Message::CountdownFinished(sound.clone())
+                        }));
+                        countdown.reset();
+                        changed = true;
+                    }
+                }
+                if changed {
+                    countdowns::save(&self.countdowns);
+                }
+            }
+            TimersMessage::NameChanged(name) => {
+                self.new_name = name;
+            }
+            TimersMessage::MinutesChanged(minutes) => {
+                self.new_minutes = minutes;
+                self.new_minutes_str = format!("{} {}", minutes, fl!("minutes"));
+            }
+            TimersMessage::SoundChanged(sound) => {
+                self.new_sound = sound;
+            }
+            TimersMessage::Add => {
+                if self.can_add() {
+                    let mut countdown = Countdown::new(
+                        self.new_name.trim().to_string(),
+                        self.new_minutes * 60,
+                        self.new_sound.trim().to_string(),
+                    );
+                    countdown.start(now);
+                    self.countdowns.push(countdown);
+                    self.new_name.clear();
+                    countdowns::save(&self.countdowns);
+                }
+            }
+            TimersMessage::Start(index) => {
+                if let Some(countdown) = self.countdowns.get_mut(index) {
+                    countdown.start(now);
+                    countdowns::save(&self.countdowns);
+                }
+            }
+            TimersMessage::Pause(index) => {
+                if let Some(countdown) = self.countdowns.get_mut(index) {
+                    countdown.pause(now);
+                    countdowns::save(&self.countdowns);
+                }
+            }
+            TimersMessage::Reset(index) => {
+                if let Some(countdown) = self.countdowns.get_mut(index) {
+                    countdown.reset();
+                    countdowns::save(&self.countdowns);
+                }
+            }
+            TimersMessage::Remove(index) => {
+                if index < self.countdowns.len() {
+                    self.countdowns.remove(index);
+                    countdowns::save(&self.countdowns);
+                }
+            }
+        }
+        Task::batch(commands)
+    }
+
+    /// Whether any countdown needs the clock to tick.
+    pub fn is_running(&self) -> bool {
+        self.countdowns.iter().any(Countdown::is_running)
+    }
+
+    fn can_add(&self) -> bool {
+        !self.new_name.trim().is_empty()
+    }
+}
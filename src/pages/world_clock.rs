@@ -0,0 +1,360 @@
+use chrono::{Local, NaiveDateTime, TimeZone as _, Timelike};
+use cosmic::iced::Alignment;
+use cosmic::{
+    iced::{Length, Padding},
+    widget::{self, icon},
+    Element, Task,
+};
+
+use crate::core::tz::{self, TimeZone};
+use crate::{app::Message, config::Config, fl};
+
+/// Zones suggested at once while searching.
+const MAX_MATCHES: usize = 8;
+
+#[derive(Debug, Clone)]
+pub enum WorldClockMessage {
+    /// Refreshes the clocks.
+    Tick,
+    SearchChanged(String),
+    Pin(String),
+    Unpin(usize),
+    PlannerHourChanged(u32),
+    WorkStartChanged(u32),
+    WorkEndChanged(u32),
+}
+
+struct Clock {
+    name: String,
+    /// Missing when the zone couldn't be read from the tz database.
+    zone: Option<TimeZone>,
+}
+
+impl Clock {
+    fn new(name: String) -> Self {
+        let zone = TimeZone::load(&name)
+            .map_err(|err| log::warn!("failed to load time zone '{name}': {err}"))
+            .ok();
+        Self { name, zone }
+    }
+
+    /// Wall clock time in the zone at `time`, with its abbreviation.
+    fn at(&self, time: i64) -> Option<(NaiveDateTime, String)> {
+        let local_type = self.zone.as_ref()?.local_type(time);
+        let wall = chrono::DateTime::from_timestamp(time + local_type.offset as i64, 0)?;
+        Some((wall.naive_utc(), local_type.abbreviation))
+    }
+}
+
+pub struct WorldClock {
+    clocks: Vec<Clock>,
+    zone_names: Vec<String>,
+    search: String,
+    /// Local hour shown across the pinned zones by the meeting planner.
+    planner_hour: u32,
+    planner_hour_str: String,
+    work_start: u32,
+    work_start_str: String,
+    work_end: u32,
+    work_end_str: String,
+}
+
+impl Default for WorldClock {
+    fn default() -> Self {
+        let config = Config::load().1;
+        let planner_hour = 15;
+        Self {
+            clocks: config.world_clocks.into_iter().map(Clock::new).collect(),
+            zone_names: tz::zone_names(),
+            search: String::new(),
+            planner_hour,
+            planner_hour_str: format_hour(planner_hour),
+            work_start: config.working_hours.0,
+            work_start_str: format_hour(config.working_hours.0),
+            work_end: config.working_hours.1,
+            work_end_str: format_hour(config.working_hours.1),
+        }
+    }
+}
+
+impl WorldClock {
+    pub fn view<'a>(&'a self) -> Element<'a, WorldClockMessage> {
+        let col = widget::column()
+            .push(widget::text::title1(fl!("world-clock")))
+            .push(widget::Space::with_height(10))
+            .push(self.clocks_view())
+            .push(widget::Space::with_height(20))
+            .push(self.planner_view())
+            .align_x(Alignment::Center);
+
+        widget::scrollable(
+            widget::container(col)
+                .width(Length::Fill)
+                .height(Length::Shrink)
+                .align_x(Alignment::Center),
+        )
+        .into()
+    }
+
+    fn clocks_view<'a>(&'a self) -> Element<'a, WorldClockMessage> {
+        let now = Local::now();
+        let time = now.timestamp();
+
+        let mut inner_col = widget::column()
+            .push(
+                widget::row()
+                    .push(widget::text::text(fl!("world-clock-local")).width(Length::Fill))
+                    .push(widget::text::title4(now.format("%H:%M").to_string())),
+            )
+            .push(widget::Space::with_height(5))
+            .push(widget::divider::horizontal::default())
+            .push(widget::Space::with_height(5));
+
+        for (index, clock) in self.clocks.iter().enumerate() {
+            let details = clock.at(time).map(|(wall, abbreviation)| {
+                (
+                    wall.format("%H:%M").to_string(),
+                    format!(
+                        "{} · {} · {}",
+                        abbreviation,
+                        format_day(wall, now.naive_local()),
+                        format_difference(wall, now.naive_local())
+                    ),
+                    self.is_working_hour(wall.hour()),
+                )
+            });
+            let (wall_time, caption, working) =
+                details.unwrap_or_else(|| ("--:--".into(), fl!("world-clock-unavailable"), false));
+
+            inner_col = inner_col.push(
+                widget::row()
+                    .push(
+                        widget::column()
+                            .push(widget::text::text(city(&clock.name)))
+                            .push(widget::text::caption(caption))
+                            .width(Length::Fill),
+                    )
+                    .push(working_icon(working))
+                    .push(widget::text::title4(wall_time))
+                    .push(
+                        widget::button::icon(icon::from_name("user-trash-symbolic"))
+                            .on_press(WorldClockMessage::Unpin(index)),
+                    )
+                    .spacing(10)
+                    .align_y(Alignment::Center),
+            );
+            inner_col = inner_col.push(widget::Space::with_height(5));
+            inner_col = inner_col.push(widget::divider::horizontal::default());
+            inner_col = inner_col.push(widget::Space::with_height(5));
+        }
+
+        inner_col = inner_col.push(
+            widget::text_input(fl!("world-clock-search"), &self.search)
+                .on_input(WorldClockMessage::SearchChanged),
+        );
+        for name in self.matches() {
+            inner_col = inner_col.push(
+                widget::button::text(name.replace('_', " "))
+                    .on_press(WorldClockMessage::Pin(name.clone())),
+            );
+        }
+
+        widget::container(widget::column().push(inner_col).padding(Padding::from(10)))
+            .class(cosmic::theme::Container::Card)
+            .width(Length::Fixed(350.))
+            .into()
+    }
+
+    /// The pinned zones at the same local hour, to find a time that suits everyone.
+    fn planner_view<'a>(&'a self) -> Element<'a, WorldClockMessage> {
+        let now = Local::now();
+        let time = now
+            .date_naive()
+            .and_hms_opt(self.planner_hour, 0, 0)
+            .and_then(|wall| Local.from_local_datetime(&wall).earliest());
+
+        let mut inner_col = widget::column()
+            .push(
+                widget::row()
+                    .push(widget::text::text(fl!("world-clock-planner")).width(Length::Fill))
+                    .push(widget::spin_button(
+                        self.planner_hour_str.clone(),
+                        self.planner_hour,
+                        1,
+                        0,
+                        23,
+                        WorldClockMessage::PlannerHourChanged,
+                    ))
+                    .align_y(Alignment::Center),
+            )
+            .push(widget::Space::with_height(5));
+
+        if let Some(time) = time {
+            for clock in &self.clocks {
+                let Some((wall, _)) = clock.at(time.timestamp()) else {
+                    continue;
+                };
+                inner_col = inner_col.push(
+                    widget::row()
+                        .push(
+                            widget::column()
+                                .push(widget::text::text(city(&clock.name)))
+                                .push(widget::text::caption(format_day(wall, time.naive_local())))
+                                .width(Length::Fill),
+                        )
+                        .push(working_icon(self.is_working_hour(wall.hour())))
+                        .push(widget::text::text(wall.format("%H:%M").to_string()))
+                        .spacing(10)
+                        .align_y(Alignment::Center),
+                );
+                inner_col = inner_col.push(widget::Space::with_height(5));
+            }
+        }
+
+        inner_col = inner_col
+            .push(widget::divider::horizontal::default())
+            .push(widget::Space::with_height(5))
+            .push(
+                widget::row()
+                    .push(widget::text::text(fl!("working-hours")).width(Length::Fill))
+                    .push(widget::spin_button(
+                        self.work_start_str.clone(),
+                        self.work_start,
+                        1,
+                        0,
+                        23,
+                        WorldClockMessage::WorkStartChanged,
+                    ))
+                    .push(widget::text::text("–"))
+                    .push(widget::spin_button(
+                        self.work_end_str.clone(),
+                        self.work_end,
+                        1,
+                        0,
+                        24,
+                        WorldClockMessage::WorkEndChanged,
+                    ))
+                    .spacing(5)
+                    .align_y(Alignment::Center),
+            );
+
+        widget::container(widget::column().push(inner_col).padding(Padding::from(10)))
+            .class(cosmic::theme::Container::Card)
+            .width(Length::Fixed(350.))
+            .into()
+    }
+
+    pub fn update(&mut self, message: WorldClockMessage) -> Task<Message> {
+        match message {
+            WorldClockMessage::Tick => {}
+            WorldClockMessage::SearchChanged(search) => {
+                self.search = search;
+            }
+            WorldClockMessage::Pin(name) => {
+                if !self.clocks.iter().any(|clock| clock.name == name) {
+                    self.clocks.push(Clock::new(name));
+                    self.save_clocks();
+                }
+                self.search.clear();
+            }
+            WorldClockMessage::Unpin(index) => {
+                if index < self.clocks.len() {
+                    self.clocks.remove(index);
+                    self.save_clocks();
+                }
+            }
+            WorldClockMessage::PlannerHourChanged(hour) => {
+                self.planner_hour = hour;
+                self.planner_hour_str = format_hour(hour);
+            }
+            WorldClockMessage::WorkStartChanged(hour) => {
+                self.work_start = hour;
+                self.work_start_str = format_hour(hour);
+                self.save_working_hours();
+            }
+            WorldClockMessage::WorkEndChanged(hour) => {
+                self.work_end = hour;
+                self.work_end_str = format_hour(hour);
+                self.save_working_hours();
+            }
+        }
+        Task::none()
+    }
+
+    fn matches(&self) -> Vec<&String> {
+        let search = self.search.trim().to_lowercase().replace(' ', "_");
+        if search.is_empty() {
+            return Vec::new();
+        }
+        self.zone_names
+            .iter()
+            .filter(|name| name.to_lowercase().contains(&search))
+            .take(MAX_MATCHES)
+            .collect()
+    }
+
+    /// Whether `hour` falls within working hours, which may span midnight.
+    fn is_working_hour(&self, hour: u32) -> bool {
+        if self.work_start <= self.work_end {
+            self.work_start <= hour && hour < self.work_end
+        } else {
+            hour >= self.work_start || hour < self.work_end
+        }
+    }
+
+    fn save_clocks(&self) {
+        let names = self.clocks.iter().map(|clock| clock.name.clone()).collect();
+        let mut config = Config::load();
+        let _ = config.1.set_world_clocks(&config.0.unwrap(), names);
+    }
+
+    fn save_working_hours(&self) {
+        let mut config = Config::load();
+        let _ = config
+            .1
+            .set_working_hours(&config.0.unwrap(), (self.work_start, self.work_end));
+    }
+}
+
+fn working_icon<'a>(working: bool) -> Element<'a, WorldClockMessage> {
+    let name = if working {
+        "weather-clear-symbolic"
+    } else {
+        "weather-clear-night-symbolic"
+    };
+    icon::from_name(name).size(16).icon().into()
+}
+
+/// The city part of a zone name, `America/Sao_Paulo` giving `Sao Paulo`.
+fn city(name: &str) -> String {
+    name.rsplit('/').next().unwrap_or(name).replace('_', " ")
+}
+
+fn format_hour(hour: u32) -> String {
+    format!("{hour:02}:00")
+}
+
+/// The day at `wall` relative to the local day at `local`.
+fn format_day(wall: NaiveDateTime, local: NaiveDateTime) -> String {
+    match (wall.date() - local.date()).num_days() {
+        0 => fl!("today"),
+        1 => fl!("tomorrow"),
+        -1 => fl!("yesterday"),
+        days if days > 0 => fl!("days-ahead", days = days),
+        days => fl!("days-behind", days = -days),
+    }
+}
+
+/// Hours ahead of or behind local time, like `+5:30`.
+fn format_difference(wall: NaiveDateTime, local: NaiveDateTime) -> String {
+    // Both are read at the same instant, so seconds don't matter
+    let minutes = (wall - local).num_minutes();
+    let rounded = (minutes as f64 / 15.).round() as i64 * 15;
+    let sign = if rounded < 0 { '-' } else { '+' };
+    let (hours, minutes) = (rounded.abs() / 60, rounded.abs() % 60);
+    if minutes == 0 {
+        format!("{sign}{hours} h")
+    } else {
+        format!("{sign}{hours}:{minutes:02} h")
+    }
+}